@@ -1,20 +1,37 @@
 //! See the `eventric-surface` crate for full documentation, including
 //! module-level documentation.
 
-use std::ops::{
-    Deref,
-    DerefMut,
+pub mod composite;
+
+use std::{
+    any::{
+        self,
+        Any,
+        TypeId,
+    },
+    fmt::Debug,
+    ops::{
+        Deref,
+        DerefMut,
+    },
 };
 
 use eventric_stream::{
-    error::Error,
+    event::CandidateEvent,
     stream::select::{
         EventAndMask,
-        Selections,
+        Selection,
     },
 };
 
-use crate::event::Events;
+use crate::{
+    error::Error,
+    event::{
+        AnyEvent,
+        Events,
+    },
+    projection::Selectors,
+};
 
 // =================================================================================================
 // Action
@@ -22,7 +39,7 @@ use crate::event::Events;
 
 // Action
 
-pub trait Action: Act + Context + Select + Update {}
+pub trait Action: Act + Context + Invariants + Select + Update {}
 
 // Act
 
@@ -34,6 +51,10 @@ where
     type Ok = ();
 
     fn action(&mut self, context: &mut Self::Context) -> Result<Self::Ok, Self::Err>;
+
+    fn validate(&self) -> Result<(), Self::Err> {
+        Ok(())
+    }
 }
 
 // Context
@@ -43,18 +64,144 @@ where
     Self::Context: Deref<Target = Events> + DerefMut + Into<Events>,
 {
     type Context;
+    type Deps = ();
 
-    fn context(&self) -> Self::Context;
+    fn context(&self, deps: Self::Deps) -> Result<Self::Context, Error>;
+}
+
+// Decide
+
+pub trait Decide: Context
+where
+    Self::Err: From<Error>,
+{
+    type Err;
+
+    fn decide(&self, state: &Self::Context) -> Result<Vec<AnyEvent>, Self::Err>;
+}
+
+// Invariants
+
+pub trait Invariants: Act {
+    const INVARIANTS: &'static [Invariant] = &[];
+
+    fn check(&self, _: &Self::Context) -> Result<(), Self::Err> {
+        Ok(())
+    }
 }
 
 // Select
 
 pub trait Select: Context {
-    fn select(&self, context: &Self::Context) -> Result<Selections, Error>;
+    fn select(&self, context: &Self::Context) -> Result<Vec<Selection>, Error>;
+
+    fn selectors(&self, context: &Self::Context) -> Result<Vec<Selectors>, Error> {
+        self.select(context)
+            .map(|selections| selections.iter().map(|_| Selectors::any()).collect())
+    }
 }
 
 // Update
 
 pub trait Update: Context {
-    fn update(&self, context: &mut Self::Context, event: &EventAndMask) -> Result<(), Error>;
+    fn update(
+        &self,
+        context: &mut Self::Context,
+        event: &EventAndMask,
+        selectors: &[Selectors],
+        offset: usize,
+    ) -> Result<usize, Error>;
+
+    fn update_candidate(
+        &self,
+        _: &mut Self::Context,
+        _: &CandidateEvent,
+        _: &[Selectors],
+        _: usize,
+    ) -> Result<usize, Error> {
+        Err(Error::Candidate(any::type_name::<Self>()))
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Any Action
+
+#[derive(Debug)]
+pub struct AnyAction {
+    action: Box<dyn Erased>,
+    name: &'static str,
+}
+
+impl AnyAction {
+    pub fn new<A>(action: A) -> Self
+    where
+        A: Action + Debug + 'static,
+    {
+        let action = Box::new(action);
+        let name = any::type_name::<A>();
+
+        Self { action, name }
+    }
+
+    #[must_use]
+    pub fn downcast<A>(self) -> Option<A>
+    where
+        A: 'static,
+    {
+        let action: Box<dyn Any> = self.action;
+
+        action.downcast().ok().map(|action| *action)
+    }
+
+    #[must_use]
+    pub fn downcast_ref<A>(&self) -> Option<&A>
+    where
+        A: 'static,
+    {
+        (&*self.action as &dyn Any).downcast_ref()
+    }
+
+    #[must_use]
+    pub fn is<A>(&self) -> bool
+    where
+        A: 'static,
+    {
+        (&*self.action as &dyn Any).is::<A>()
+    }
+
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    #[must_use]
+    pub fn type_id(&self) -> TypeId {
+        (&*self.action as &dyn Any).type_id()
+    }
+}
+
+// Erased
+
+trait Erased: Any + Debug {}
+
+impl<A> Erased for A where A: Any + Debug {}
+
+// -------------------------------------------------------------------------------------------------
+
+// Invariant
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Invariant {
+    pub condition: &'static str,
+    pub error: &'static str,
+    pub kind: InvariantKind,
+}
+
+// Invariant Kind
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum InvariantKind {
+    Forbid,
+    Require,
 }
@@ -0,0 +1,345 @@
+//! See the `eventric-surface` crate for full documentation, including
+//! module-level documentation.
+
+use std::{
+    marker::PhantomData,
+    mem,
+    ops::{
+        Deref,
+        DerefMut,
+    },
+};
+
+use eventric_stream::{
+    event::CandidateEvent,
+    stream::select::{
+        EventAndMask,
+        Selection,
+    },
+};
+use fancy_constructor::new;
+
+use crate::{
+    action::{
+        Act,
+        Action,
+        Context,
+        Invariant,
+        InvariantKind,
+        Invariants,
+        Select,
+        Update,
+    },
+    error::{
+        CompositeError,
+        Error,
+    },
+    event::Events,
+    projection::Selectors,
+};
+
+// =================================================================================================
+// Composite
+// =================================================================================================
+
+// Composite
+
+#[derive(new, Debug)]
+pub struct Composite<A, B, D = ()> {
+    pub first: A,
+    pub second: B,
+    #[new(default)]
+    deps: PhantomData<fn() -> D>,
+}
+
+impl<A, B, D> Action for Composite<A, B, D>
+where
+    A: Action,
+    B: Action,
+    D: CompositeDeps<A, B>,
+{
+}
+
+impl<A, B, D> Clone for Composite<A, B, D>
+where
+    A: Clone,
+    B: Clone,
+{
+    fn clone(&self) -> Self {
+        Self::new(self.first.clone(), self.second.clone())
+    }
+}
+
+impl<A, B, D> Act for Composite<A, B, D>
+where
+    A: Invariants,
+    B: Act + Invariants + Select + Update,
+    D: CompositeDeps<A, B>,
+{
+    type Err = CompositeError<A::Err, B::Err>;
+    type Ok = (A::Ok, B::Ok);
+
+    fn action(&mut self, context: &mut Self::Context) -> Result<Self::Ok, Self::Err> {
+        let first = self
+            .first
+            .action(&mut context.first)
+            .map_err(CompositeError::First)?;
+
+        let selectors = self.second.selectors(&context.second)?;
+
+        for event in context.first.candidates() {
+            self.second
+                .update_candidate(&mut context.second, event, &selectors, 0)?;
+        }
+
+        context.folded = true;
+
+        self.check(context)?;
+
+        let second = self
+            .second
+            .action(&mut context.second)
+            .map_err(CompositeError::Second)?;
+
+        context.events.merge(mem::take(&mut *context.first));
+        context.events.merge(mem::take(&mut *context.second));
+
+        Ok((first, second))
+    }
+
+    fn validate(&self) -> Result<(), Self::Err> {
+        self.first.validate().map_err(CompositeError::First)?;
+        self.second.validate().map_err(CompositeError::Second)
+    }
+}
+
+impl<A, B, D> Context for Composite<A, B, D>
+where
+    A: Context,
+    B: Context,
+    D: CompositeDeps<A, B>,
+{
+    type Context = CompositeContext<A, B>;
+    type Deps = D;
+
+    fn context(&self, deps: Self::Deps) -> Result<Self::Context, Error> {
+        let (first, second) = deps.split();
+
+        let events = Events::new();
+        let first = self.first.context(first)?;
+        let second = self.second.context(second)?;
+
+        Ok(CompositeContext::new(events, first, second))
+    }
+}
+
+impl<A, B, D> Composite<A, B, D>
+where
+    A: Invariants,
+    B: Invariants,
+{
+    const INVARIANTS_BUFFER: &'static ([Invariant; INVARIANTS_MAX], usize) =
+        &concat(A::INVARIANTS, B::INVARIANTS);
+}
+
+impl<A, B, D> Invariants for Composite<A, B, D>
+where
+    A: Invariants,
+    B: Invariants + Select + Update,
+    D: CompositeDeps<A, B>,
+{
+    const INVARIANTS: &'static [Invariant] = Self::INVARIANTS_BUFFER
+        .0
+        .split_at(Self::INVARIANTS_BUFFER.1)
+        .0;
+
+    fn check(&self, context: &Self::Context) -> Result<(), Self::Err> {
+        self.first
+            .check(&context.first)
+            .map_err(CompositeError::First)?;
+
+        if context.folded {
+            self.second
+                .check(&context.second)
+                .map_err(CompositeError::Second)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<A, B, D> Select for Composite<A, B, D>
+where
+    A: Select,
+    B: Select,
+    D: CompositeDeps<A, B>,
+{
+    fn select(&self, context: &Self::Context) -> Result<Vec<Selection>, Error> {
+        let mut selections = self.first.select(&context.first)?;
+
+        selections.extend(self.second.select(&context.second)?);
+
+        Ok(selections)
+    }
+
+    fn selectors(&self, context: &Self::Context) -> Result<Vec<Selectors>, Error> {
+        let mut selectors = self.first.selectors(&context.first)?;
+
+        selectors.extend(self.second.selectors(&context.second)?);
+
+        Ok(selectors)
+    }
+}
+
+impl<A, B, D> Update for Composite<A, B, D>
+where
+    A: Update,
+    B: Update,
+    D: CompositeDeps<A, B>,
+{
+    fn update(
+        &self,
+        context: &mut Self::Context,
+        event: &EventAndMask,
+        selectors: &[Selectors],
+        offset: usize,
+    ) -> Result<usize, Error> {
+        let offset = self
+            .first
+            .update(&mut context.first, event, selectors, offset)?;
+
+        self.second
+            .update(&mut context.second, event, selectors, offset)
+    }
+
+    fn update_candidate(
+        &self,
+        context: &mut Self::Context,
+        event: &CandidateEvent,
+        selectors: &[Selectors],
+        offset: usize,
+    ) -> Result<usize, Error> {
+        let offset = self
+            .first
+            .update_candidate(&mut context.first, event, selectors, offset)?;
+
+        self.second
+            .update_candidate(&mut context.second, event, selectors, offset)
+    }
+}
+
+// Composite Context
+
+#[derive(new, Debug)]
+pub struct CompositeContext<A, B>
+where
+    A: Context,
+    B: Context,
+{
+    events: Events,
+    pub first: A::Context,
+    pub second: B::Context,
+    #[new(default)]
+    folded: bool,
+}
+
+impl<A, B> Deref for CompositeContext<A, B>
+where
+    A: Context,
+    B: Context,
+{
+    type Target = Events;
+
+    fn deref(&self) -> &Self::Target {
+        &self.events
+    }
+}
+
+impl<A, B> DerefMut for CompositeContext<A, B>
+where
+    A: Context,
+    B: Context,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.events
+    }
+}
+
+impl<A, B> From<CompositeContext<A, B>> for Events
+where
+    A: Context,
+    B: Context,
+{
+    fn from(context: CompositeContext<A, B>) -> Self {
+        let mut events = context.first.into();
+
+        events.merge(context.second.into());
+        events.merge(context.events);
+        events
+    }
+}
+
+// Composite Deps
+
+pub trait CompositeDeps<A, B>
+where
+    A: Context,
+    B: Context,
+{
+    fn split(self) -> (A::Deps, B::Deps);
+}
+
+impl<A, B> CompositeDeps<A, B> for ()
+where
+    A: Context<Deps = ()>,
+    B: Context<Deps = ()>,
+{
+    fn split(self) -> (A::Deps, B::Deps) {
+        ((), ())
+    }
+}
+
+impl<A, B> CompositeDeps<A, B> for (A::Deps, B::Deps)
+where
+    A: Context,
+    B: Context,
+{
+    fn split(self) -> (A::Deps, B::Deps) {
+        self
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Invariants
+
+const INVARIANTS_MAX: usize = 64;
+
+const fn concat(first: &[Invariant], second: &[Invariant]) -> ([Invariant; INVARIANTS_MAX], usize) {
+    assert!(
+        first.len() + second.len() <= INVARIANTS_MAX,
+        "composite actions support at most 64 invariants"
+    );
+
+    let mut invariants = [Invariant {
+        condition: "",
+        error: "",
+        kind: InvariantKind::Forbid,
+    }; INVARIANTS_MAX];
+
+    let mut i = 0;
+
+    while i < first.len() {
+        invariants[i] = first[i];
+        i += 1;
+    }
+
+    let mut j = 0;
+
+    while j < second.len() {
+        invariants[i + j] = second[j];
+        j += 1;
+    }
+
+    (invariants, i + j)
+}
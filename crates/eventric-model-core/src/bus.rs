@@ -0,0 +1,213 @@
+//! See the `eventric-surface` crate for full documentation, including
+//! module-level documentation.
+
+use std::{
+    any::Any,
+    panic::{
+        self,
+        AssertUnwindSafe,
+    },
+    sync::mpsc::{
+        self,
+        Receiver,
+        SyncSender,
+        TryRecvError,
+        TrySendError,
+    },
+    thread::{
+        self,
+        JoinHandle,
+    },
+};
+
+use crate::{
+    action::{
+        Action,
+        Context,
+    },
+    core::Enactor,
+    error::{
+        BusError,
+        Error,
+    },
+};
+
+// =================================================================================================
+// Bus
+// =================================================================================================
+
+// Command Bus
+
+type Job<S> = Box<dyn FnOnce(&mut S) + Send>;
+
+#[derive(Debug)]
+pub struct CommandBus<S> {
+    sender: Option<SyncSender<Job<S>>>,
+    worker: Option<JoinHandle<()>>,
+}
+
+impl<S> CommandBus<S>
+where
+    S: Enactor + Send + 'static,
+{
+    #[must_use]
+    pub fn new(stream: S, capacity: usize) -> Self {
+        let (sender, receiver) = mpsc::sync_channel::<Job<S>>(capacity);
+
+        let worker = thread::spawn(move || {
+            let mut stream = stream;
+
+            for job in receiver {
+                job(&mut stream);
+            }
+        });
+
+        Self {
+            sender: Some(sender),
+            worker: Some(worker),
+        }
+    }
+
+    pub fn enact<A>(&self, action: A) -> Result<A::Ok, A::Err>
+    where
+        A: Action + Context<Deps = ()> + Send + 'static,
+        A::Ok: Send,
+        A::Err: Send,
+    {
+        self.submit(action).map_err(Error::from)?.wait()
+    }
+
+    pub fn enact_with<A>(&self, action: A, deps: A::Deps) -> Result<A::Ok, A::Err>
+    where
+        A: Action + Send + 'static,
+        A::Deps: Send,
+        A::Ok: Send,
+        A::Err: Send,
+    {
+        self.submit_with(action, deps).map_err(Error::from)?.wait()
+    }
+
+    pub fn submit<A>(&self, action: A) -> Result<Reply<A::Ok, A::Err>, BusError>
+    where
+        A: Action + Context<Deps = ()> + Send + 'static,
+        A::Ok: Send,
+        A::Err: Send,
+    {
+        self.submit_with(action, ())
+    }
+
+    pub fn submit_with<A>(&self, action: A, deps: A::Deps) -> Result<Reply<A::Ok, A::Err>, BusError>
+    where
+        A: Action + Send + 'static,
+        A::Deps: Send,
+        A::Ok: Send,
+        A::Err: Send,
+    {
+        let (job, reply) = job(action, deps);
+
+        self.sender()?.send(job).map_err(|_| BusError::Closed)?;
+
+        Ok(reply)
+    }
+
+    pub fn try_submit<A>(&self, action: A) -> Result<Reply<A::Ok, A::Err>, BusError>
+    where
+        A: Action + Context<Deps = ()> + Send + 'static,
+        A::Ok: Send,
+        A::Err: Send,
+    {
+        self.try_submit_with(action, ())
+    }
+
+    pub fn try_submit_with<A>(
+        &self,
+        action: A,
+        deps: A::Deps,
+    ) -> Result<Reply<A::Ok, A::Err>, BusError>
+    where
+        A: Action + Send + 'static,
+        A::Deps: Send,
+        A::Ok: Send,
+        A::Err: Send,
+    {
+        let (job, reply) = job(action, deps);
+
+        self.sender()?.try_send(job).map_err(|err| match err {
+            TrySendError::Disconnected(_) => BusError::Closed,
+            TrySendError::Full(_) => BusError::Full,
+        })?;
+
+        Ok(reply)
+    }
+
+    fn sender(&self) -> Result<&SyncSender<Job<S>>, BusError> {
+        self.sender.as_ref().ok_or(BusError::Closed)
+    }
+}
+
+impl<S> Drop for CommandBus<S> {
+    fn drop(&mut self) {
+        self.sender.take();
+
+        if let Some(worker) = self.worker.take() {
+            worker.join().ok();
+        }
+    }
+}
+
+fn job<S, A>(action: A, deps: A::Deps) -> (Job<S>, Reply<A::Ok, A::Err>)
+where
+    S: Enactor,
+    A: Action + Send + 'static,
+    A::Deps: Send,
+    A::Ok: Send,
+    A::Err: Send,
+{
+    let (sender, receiver) = mpsc::sync_channel(1);
+
+    let job: Job<S> = Box::new(move |stream| {
+        let outcome = panic::catch_unwind(AssertUnwindSafe(|| stream.enact_with(action, deps)))
+            .unwrap_or_else(|payload| {
+                Err(Error::from(BusError::Panicked(panic_message(&*payload))).into())
+            });
+
+        sender.send(outcome).ok();
+    });
+
+    (job, Reply { receiver })
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(ToString::to_string)
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_default()
+}
+
+// Reply
+
+#[derive(Debug)]
+pub struct Reply<O, E> {
+    receiver: Receiver<Result<O, E>>,
+}
+
+impl<O, E> Reply<O, E>
+where
+    E: From<Error>,
+{
+    #[must_use]
+    pub fn try_wait(&self) -> Option<Result<O, E>> {
+        match self.receiver.try_recv() {
+            Ok(result) => Some(result),
+            Err(TryRecvError::Disconnected) => Some(Err(Error::from(BusError::Closed).into())),
+            Err(TryRecvError::Empty) => None,
+        }
+    }
+
+    pub fn wait(self) -> Result<O, E> {
+        self.receiver
+            .recv()
+            .unwrap_or_else(|_| Err(Error::from(BusError::Closed).into()))
+    }
+}
@@ -1,9 +1,48 @@
-use eventric_stream::stream::{
-    append::AppendSelect,
-    iterate::IterateSelect,
+use std::{
+    any::{
+        self,
+        Any,
+        TypeId,
+    },
+    collections::{
+        BTreeMap,
+        VecDeque,
+    },
+    fmt::Debug,
+    sync::Arc,
 };
 
-use crate::action::Action;
+use eventric_stream::{
+    event::{
+        CandidateEvent,
+        Position,
+    },
+    stream::{
+        append::{
+            Append,
+            AppendSelect,
+        },
+        iterate::IterateSelect,
+        select::{
+            EventAndMask,
+            Selections,
+        },
+    },
+};
+
+use crate::{
+    action::{
+        Act,
+        Action,
+        AnyAction,
+        Context,
+    },
+    error::{
+        Error,
+        RegistryError,
+    },
+    projection::Selectors,
+};
 
 // =================================================================================================
 // Core
@@ -13,24 +52,225 @@ use crate::action::Action;
 
 pub trait Enactor {
     fn enact<A>(&mut self, action: A) -> Result<A::Ok, A::Err>
+    where
+        A: Action + Context<Deps = ()>,
+    {
+        self.enact_with(action, ())
+    }
+
+    fn enact_batch<A, I>(&mut self, actions: I) -> Vec<Result<A::Ok, A::Err>>
+    where
+        A: Action + Context<Deps = ()>,
+        I: IntoIterator<Item = A>;
+
+    fn enact_with<A>(&mut self, action: A, deps: A::Deps) -> Result<A::Ok, A::Err>
+    where
+        A: Action,
+    {
+        self.try_enact_with(action, deps)
+            .unwrap_or_else(|err| Err(err.into()))
+    }
+
+    fn try_enact_with<A>(
+        &mut self,
+        action: A,
+        deps: A::Deps,
+    ) -> Result<Result<A::Ok, A::Err>, Error>
     where
         A: Action;
 }
 
 impl<T> Enactor for T
 where
-    T: AppendSelect + IterateSelect,
+    T: Append + AppendSelect + IterateSelect,
 {
-    fn enact<A>(&mut self, mut action: A) -> Result<A::Ok, A::Err>
+    fn enact_batch<A, I>(&mut self, actions: I) -> Vec<Result<A::Ok, A::Err>>
+    where
+        A: Action + Context<Deps = ()>,
+        I: IntoIterator<Item = A>,
+    {
+        enact_batch(self, actions, None)
+    }
+
+    fn try_enact_with<A>(
+        &mut self,
+        action: A,
+        deps: A::Deps,
+    ) -> Result<Result<A::Ok, A::Err>, Error>
     where
         A: Action,
     {
-        let mut after = None;
-        let mut context = action.context();
+        try_enact(self, action, deps, None)
+    }
+}
+
+// Dyn Enactor
+
+pub trait DynEnactor {
+    fn enact_any(&mut self, action: AnyAction) -> Result<Box<dyn Any>, Error>;
+}
+
+pub trait DynEnactorExt: DynEnactor {
+    fn enact<A>(&mut self, action: A) -> Result<A::Ok, A::Err>
+    where
+        A: Action + Debug + 'static,
+    {
+        self.enact_any(AnyAction::new(action))?
+            .downcast::<Result<A::Ok, A::Err>>()
+            .map_or_else(
+                |_| Err(Error::from(RegistryError::Unhandled(any::type_name::<A>())).into()),
+                |outcome| *outcome,
+            )
+    }
+}
+
+impl<T> DynEnactorExt for T where T: DynEnactor + ?Sized {}
+
+// Registry
+
+type Handler<S> = fn(&mut S, AnyAction) -> Result<Box<dyn Any>, Error>;
+
+#[derive(Debug)]
+pub struct Registry<S> {
+    pub stream: S,
+    handlers: BTreeMap<TypeId, Handler<S>>,
+}
+
+impl<S> Registry<S>
+where
+    S: Enactor,
+{
+    #[must_use]
+    pub fn new(stream: S) -> Self {
+        let handlers = BTreeMap::new();
+
+        Self { stream, handlers }
+    }
+
+    #[must_use]
+    pub fn register<A>(mut self) -> Self
+    where
+        A: Action + Context<Deps = ()> + 'static,
+    {
+        self.handlers.insert(TypeId::of::<A>(), handle::<S, A>);
+        self
+    }
+}
+
+impl<S> DynEnactor for Registry<S> {
+    fn enact_any(&mut self, action: AnyAction) -> Result<Box<dyn Any>, Error> {
+        match self.handlers.get(&action.type_id()) {
+            Some(handler) => handler(&mut self.stream, action),
+            None => Err(RegistryError::Unhandled(action.name()).into()),
+        }
+    }
+}
+
+fn handle<S, A>(stream: &mut S, action: AnyAction) -> Result<Box<dyn Any>, Error>
+where
+    S: Enactor,
+    A: Action + Context<Deps = ()> + 'static,
+{
+    let name = action.name();
+    let action = action
+        .downcast::<A>()
+        .ok_or(RegistryError::Unhandled(name))?;
+
+    Ok(Box::new(stream.enact(action)))
+}
+
+// Recording Enactor
+
+#[derive(Debug, Default)]
+pub struct RecordingEnactor {
+    pub actions: Vec<AnyAction>,
+    results: BTreeMap<TypeId, VecDeque<Box<dyn Any>>>,
+}
+
+impl RecordingEnactor {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn recorded<A>(&self) -> impl Iterator<Item = &A>
+    where
+        A: 'static,
+    {
+        self.actions.iter().filter_map(AnyAction::downcast_ref)
+    }
+
+    #[must_use]
+    pub fn script<A>(mut self, result: Result<A::Ok, A::Err>) -> Self
+    where
+        A: Action + 'static,
+    {
+        self.results
+            .entry(TypeId::of::<A>())
+            .or_default()
+            .push_back(Box::new(result));
+        self
+    }
+}
+
+impl DynEnactor for RecordingEnactor {
+    fn enact_any(&mut self, action: AnyAction) -> Result<Box<dyn Any>, Error> {
+        let name = action.name();
+        let result = self
+            .results
+            .get_mut(&action.type_id())
+            .and_then(VecDeque::pop_front);
+
+        self.actions.push(action);
+
+        result.ok_or_else(|| RegistryError::Unhandled(name).into())
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Enact
+
+pub(crate) type Observer<'a, 'b> = Option<&'a mut (dyn FnMut(&CandidateEvent, Position) + 'b)>;
+
+pub(crate) fn enact<T, A>(
+    stream: &mut T,
+    action: A,
+    deps: A::Deps,
+    observer: Observer<'_, '_>,
+) -> Result<A::Ok, A::Err>
+where
+    T: Append + AppendSelect + IterateSelect,
+    A: Action,
+{
+    try_enact(stream, action, deps, observer).unwrap_or_else(|err| Err(err.into()))
+}
+
+pub(crate) fn try_enact<T, A>(
+    stream: &mut T,
+    mut action: A,
+    deps: A::Deps,
+    observer: Observer<'_, '_>,
+) -> Result<Result<A::Ok, A::Err>, Error>
+where
+    T: Append + AppendSelect + IterateSelect,
+    A: Action,
+{
+    if let Err(err) = action.validate() {
+        return Ok(Err(err));
+    }
 
-        let selections = action.select(&context)?;
+    let mut after = None;
+    let mut select = None;
+    let mut context = action.context(deps)?;
 
-        let (events, select) = self.iter_select(selections, None);
+    let selections = action.select(&context)?;
+
+    if !selections.is_empty() {
+        let selections = Selections::new(selections).map_err(Error::from)?;
+        let selectors = action.selectors(&context)?;
+
+        let (events, events_select) = stream.iter_select(selections, None);
 
         for event in events {
             let event_and_mask = event?;
@@ -38,16 +278,278 @@ where
 
             after = Some(position);
 
-            action.update(&mut context, &event_and_mask)?;
+            action.update(&mut context, &event_and_mask, &selectors, 0)?;
+        }
+
+        select = Some(events_select);
+    }
+
+    let ok = match action
+        .check(&context)
+        .and_then(|()| action.action(&mut context))
+    {
+        Ok(ok) => ok,
+        Err(err) => return Ok(Err(err)),
+    };
+
+    let events = context.into().take();
+
+    if !events.is_empty() {
+        let position = match select {
+            Some(select) => stream.append_select(events.iter().cloned(), select, after),
+            None => stream.append(events.iter().cloned(), after),
+        }?;
+
+        observe(observer, &events, position);
+    }
+
+    Ok(Ok(ok))
+}
+
+fn observe(observer: Observer<'_, '_>, events: &[CandidateEvent], position: Position) {
+    if let Some(observer) = observer {
+        for event in events {
+            observer(event, position);
+        }
+    }
+}
+
+// Batch
+
+type Outcomes<A> = Vec<Option<Result<<A as Act>::Ok, <A as Act>::Err>>>;
+
+pub(crate) fn enact_batch<T, A, I>(
+    stream: &mut T,
+    actions: I,
+    observer: Observer<'_, '_>,
+) -> Vec<Result<A::Ok, A::Err>>
+where
+    T: Append + AppendSelect + IterateSelect,
+    A: Action + Context<Deps = ()>,
+    I: IntoIterator<Item = A>,
+{
+    let mut outcomes = Vec::new();
+    let mut members = Vec::new();
+
+    for (index, action) in actions.into_iter().enumerate() {
+        match action.validate() {
+            Ok(()) => {
+                outcomes.push(None);
+                members.push((index, action));
+            }
+            Err(err) => outcomes.push(Some(Err(err))),
         }
+    }
+
+    enact_group(stream, members, &mut outcomes, observer);
 
-        let ok = action.action(&mut context)?;
-        let events = context.into().take();
+    outcomes.into_iter().flatten().collect()
+}
 
-        if !events.is_empty() {
-            self.append_select(events, select, after)?;
+fn enact_group<T, A>(
+    stream: &mut T,
+    members: Vec<(usize, A)>,
+    outcomes: &mut Outcomes<A>,
+    mut observer: Observer<'_, '_>,
+) where
+    T: Append + AppendSelect + IterateSelect,
+    A: Action + Context<Deps = ()>,
+{
+    let (mut members, contexts, selections, selectors, offsets) = select_group(members, outcomes);
+    let mut contexts = contexts.into_iter().map(Some).collect::<Vec<_>>();
+
+    let (after, select) = match selections {
+        Ok(Some(selections)) => {
+            let (events, select) = stream.iter_select(selections, None);
+
+            match update_group(
+                &members,
+                &mut contexts,
+                &selectors,
+                &offsets,
+                events,
+                outcomes,
+            ) {
+                Ok(after) => (after, Some(select)),
+                Err(err) => return fail_group(&members, outcomes, err),
+            }
         }
+        Ok(None) => (None, None),
+        Err(err) => return fail_group(&members, outcomes, err),
+    };
+
+    let mut decided = Vec::new();
+    let mut events = Vec::new();
+
+    for (((index, action), context), offset) in members.iter_mut().zip(&mut contexts).zip(offsets) {
+        let Some(mut context) = context.take() else {
+            continue;
+        };
 
-        Ok(ok)
+        let outcome = update_candidates(action, &mut context, &events, &selectors, offset)
+            .map_err(A::Err::from)
+            .and_then(|()| action.check(&context))
+            .and_then(|()| action.action(&mut context));
+
+        if outcome.is_ok() {
+            events.extend(context.into().take());
+        }
+
+        decided.push((*index, outcome));
+    }
+
+    let appended = match select {
+        _ if events.is_empty() => Ok(None),
+        Some(select) => stream
+            .append_select(events.iter().cloned(), select, after)
+            .map(Some),
+        None => stream.append(events.iter().cloned(), after).map(Some),
+    };
+
+    match appended {
+        Ok(position) => {
+            if let Some(position) = position {
+                observe(observer.as_deref_mut(), &events, position);
+            }
+
+            for (index, outcome) in decided {
+                outcomes[index] = Some(outcome);
+            }
+        }
+        Err(eventric_stream::error::Error::Concurrency) => {
+            members.retain(|(index, _)| outcomes[*index].is_none());
+
+            enact_sequential(stream, members, outcomes, observer);
+        }
+        Err(err) => fail_group(&members, outcomes, err.into()),
     }
 }
+
+fn enact_sequential<T, A>(
+    stream: &mut T,
+    members: Vec<(usize, A)>,
+    outcomes: &mut Outcomes<A>,
+    mut observer: Observer<'_, '_>,
+) where
+    T: Append + AppendSelect + IterateSelect,
+    A: Action + Context<Deps = ()>,
+{
+    for (index, action) in members {
+        let outcome = enact(stream, action, (), observer.as_deref_mut());
+
+        outcomes[index] = Some(outcome);
+    }
+}
+
+fn fail_group<A>(members: &[(usize, A)], outcomes: &mut Outcomes<A>, err: Error)
+where
+    A: Action,
+{
+    let err = Arc::new(err);
+
+    for (index, _) in members {
+        if outcomes[*index].is_none() {
+            outcomes[*index] = Some(Err(Error::Batch(Arc::clone(&err)).into()));
+        }
+    }
+}
+
+type Group<A> = (
+    Vec<(usize, A)>,
+    Vec<<A as Context>::Context>,
+    Result<Option<Selections>, Error>,
+    Vec<Selectors>,
+    Vec<usize>,
+);
+
+fn select_group<A>(members: Vec<(usize, A)>, outcomes: &mut Outcomes<A>) -> Group<A>
+where
+    A: Action + Context<Deps = ()>,
+{
+    let mut selected = Vec::new();
+    let mut contexts = Vec::new();
+    let mut selections = Vec::new();
+    let mut selectors = Vec::new();
+    let mut offsets = Vec::new();
+
+    for (index, action) in members {
+        let member = action.context(()).and_then(|context| {
+            let member_selections = action.select(&context)?;
+            let member_selectors = action.selectors(&context)?;
+
+            Ok((context, member_selections, member_selectors))
+        });
+
+        match member {
+            Ok((context, member_selections, member_selectors)) => {
+                offsets.push(selectors.len());
+                selections.extend(member_selections);
+                selectors.extend(member_selectors);
+                contexts.push(context);
+                selected.push((index, action));
+            }
+            Err(err) => outcomes[index] = Some(Err(err.into())),
+        }
+    }
+
+    let selections = if selections.is_empty() {
+        Ok(None)
+    } else {
+        Selections::new(selections).map(Some).map_err(Error::from)
+    };
+
+    (selected, contexts, selections, selectors, offsets)
+}
+
+fn update_group<A, E>(
+    members: &[(usize, A)],
+    contexts: &mut [Option<A::Context>],
+    selectors: &[Selectors],
+    offsets: &[usize],
+    events: E,
+    outcomes: &mut Outcomes<A>,
+) -> Result<Option<Position>, Error>
+where
+    A: Action,
+    E: IntoIterator<Item = Result<EventAndMask, eventric_stream::error::Error>>,
+{
+    let mut after = None;
+
+    for event in events {
+        let event_and_mask = event?;
+
+        after = Some(*event_and_mask.event.position());
+
+        for (((index, action), context), offset) in
+            members.iter().zip(contexts.iter_mut()).zip(offsets)
+        {
+            let Some(member_context) = context else {
+                continue;
+            };
+
+            if let Err(err) = action.update(member_context, &event_and_mask, selectors, *offset) {
+                outcomes[*index] = Some(Err(err.into()));
+                context.take();
+            }
+        }
+    }
+
+    Ok(after)
+}
+
+fn update_candidates<A>(
+    action: &A,
+    context: &mut A::Context,
+    events: &[CandidateEvent],
+    selectors: &[Selectors],
+    offset: usize,
+) -> Result<(), Error>
+where
+    A: Action,
+{
+    for event in events {
+        action.update_candidate(context, event, selectors, offset)?;
+    }
+
+    Ok(())
+}
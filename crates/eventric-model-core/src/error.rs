@@ -1,2 +1,161 @@
 //! See the `eventric-surface` crate for full documentation, including
 //! module-level documentation.
+
+use std::{
+    any,
+    error,
+    io,
+    sync::Arc,
+};
+
+use eventric_stream::event::{
+    Identifier,
+    Position,
+};
+use fancy_constructor::new;
+use thiserror::Error;
+
+// =================================================================================================
+// Error
+// =================================================================================================
+
+// Error
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("batch enactment failed")]
+    Batch(#[source] Arc<Error>),
+    #[error(transparent)]
+    Bus(#[from] BusError),
+    #[error("{0} does not support candidate events")]
+    Candidate(&'static str),
+    #[error(transparent)]
+    Checkpoint(#[from] CheckpointError),
+    #[error(transparent)]
+    Initialize(#[from] InitializeError),
+    #[error(transparent)]
+    Outbox(#[from] OutboxError),
+    #[error(transparent)]
+    Projection(#[from] ProjectionError),
+    #[error(transparent)]
+    Registry(#[from] RegistryError),
+    #[error(transparent)]
+    Schedule(#[from] ScheduleError),
+    #[error(transparent)]
+    Stream(#[from] eventric_stream::error::Error),
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Bus Error
+
+#[derive(Debug, Error)]
+pub enum BusError {
+    #[error("command bus closed")]
+    Closed,
+    #[error("command bus full")]
+    Full,
+    #[error("action panicked: {0}")]
+    Panicked(String),
+}
+
+// Checkpoint Error
+
+#[derive(Debug, Error)]
+pub enum CheckpointError {
+    #[error("checkpoint could not be read or written")]
+    Io(#[source] io::Error),
+    #[error("checkpoint {0:?} is not a position")]
+    Invalid(String),
+}
+
+// Composite Error
+
+#[derive(Debug, Error)]
+pub enum CompositeError<A, B> {
+    #[error(transparent)]
+    First(A),
+    #[error(transparent)]
+    Model(#[from] Error),
+    #[error(transparent)]
+    Second(B),
+}
+
+// Initialize Error
+
+#[derive(new, Debug, Error)]
+#[error("initializer for projection {projection} failed")]
+pub struct InitializeError {
+    pub projection: &'static str,
+    #[source]
+    pub source: Box<dyn error::Error + Send + Sync>,
+}
+
+impl InitializeError {
+    #[must_use]
+    pub fn of<P, Err>(source: Err) -> Self
+    where
+        Err: Into<Box<dyn error::Error + Send + Sync>>,
+    {
+        let projection = any::type_name::<P>();
+        let source = source.into();
+
+        Self::new(projection, source)
+    }
+}
+
+// Outbox Error
+
+#[derive(Debug, Error)]
+pub enum OutboxError {
+    #[error("outbox sink failed")]
+    Sink(#[source] Box<dyn error::Error + Send + Sync>),
+}
+
+// Projection Error
+
+#[derive(new, Debug, Error)]
+#[error("projection {projection} failed on event {identifier:?} at position {position:?}")]
+pub struct ProjectionError {
+    pub identifier: Identifier,
+    pub position: Option<Position>,
+    pub projection: &'static str,
+    #[source]
+    pub source: Box<dyn error::Error + Send + Sync>,
+}
+
+// Reaction Error
+
+#[derive(new, Debug, Error)]
+#[error("reaction to event {identifier:?} at position {position:?} failed")]
+pub struct ReactionError {
+    pub identifier: Identifier,
+    pub position: Position,
+    #[source]
+    pub source: eventric_stream::error::Error,
+}
+
+// Registry Error
+
+#[derive(Debug, Error)]
+pub enum RegistryError {
+    #[error("no handler for action {0}")]
+    Unhandled(&'static str),
+}
+
+// Schedule Error
+
+#[derive(Debug, Error)]
+pub enum ScheduleError {
+    #[error("timer {0} is not pending")]
+    NotPending(String),
+}
+
+// Transition Error
+
+#[derive(new, Debug, Error)]
+#[error("invalid transition from state {state} on event {event}")]
+pub struct TransitionError {
+    pub event: &'static str,
+    pub state: &'static str,
+}
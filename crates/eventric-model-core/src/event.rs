@@ -1,6 +1,19 @@
 //! See the `eventric-surface` crate for full documentation, including
 //! module-level documentation.
 
+use std::{
+    any::{
+        Any,
+        TypeId,
+    },
+    collections::BTreeMap,
+    fmt::Debug,
+    sync::{
+        PoisonError,
+        RwLock,
+    },
+};
+
 use eventric_stream::{
     error::Error,
     event::{
@@ -22,7 +35,10 @@ use revision::{
 
 // Event
 
-pub trait Event: DeserializeRevisioned + Identifier + Tags + SerializeRevisioned {}
+pub trait Event:
+    DeserializeRevisioned + Identifiers + Identify + Tags + SerializeRevisioned
+{
+}
 
 // Identifier
 
@@ -30,6 +46,36 @@ pub trait Identifier {
     fn identifier() -> Result<&'static event::Identifier, Error>;
 }
 
+// Identifiers
+
+pub trait Identifiers {
+    fn identifiers() -> Result<Vec<&'static event::Identifier>, Error>;
+}
+
+impl<T> Identifiers for T
+where
+    T: Identifier,
+{
+    fn identifiers() -> Result<Vec<&'static event::Identifier>, Error> {
+        T::identifier().map(|identifier| vec![identifier])
+    }
+}
+
+// Identify
+
+pub trait Identify {
+    fn identify(&self) -> Result<&'static event::Identifier, Error>;
+}
+
+impl<T> Identify for T
+where
+    T: Identifier,
+{
+    fn identify(&self) -> Result<&'static event::Identifier, Error> {
+        T::identifier()
+    }
+}
+
 // Specifier
 
 pub trait Specifier {
@@ -45,17 +91,110 @@ where
     }
 }
 
+// Specifiers
+
+pub trait Specifiers {
+    fn specifiers() -> Result<Vec<event::Specifier>, Error>;
+}
+
+impl<T> Specifiers for T
+where
+    T: Identifiers,
+{
+    fn specifiers() -> Result<Vec<event::Specifier>, Error> {
+        T::identifiers().map(|identifiers| {
+            identifiers
+                .into_iter()
+                .cloned()
+                .map(event::Specifier::new)
+                .collect()
+        })
+    }
+}
+
 // Tags
 
 pub trait Tags {
     fn tags(&self) -> Result<Vec<event::Tag>, Error>;
 }
 
+// Variants
+
+pub trait Variants {
+    fn variant(name: &str) -> Result<&'static event::Identifier, Error>;
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Any Event
+
+#[derive(Debug)]
+pub struct AnyEvent {
+    event: Box<dyn Erased>,
+}
+
+impl AnyEvent {
+    pub fn new<E>(event: E) -> Self
+    where
+        E: Debug + Event + 'static,
+    {
+        let event = Box::new(event);
+
+        Self { event }
+    }
+
+    #[must_use]
+    pub fn downcast_ref<E>(&self) -> Option<&E>
+    where
+        E: 'static,
+    {
+        self.event.as_any().downcast_ref()
+    }
+
+    #[must_use]
+    pub fn is<E>(&self) -> bool
+    where
+        E: 'static,
+    {
+        self.event.as_any().is::<E>()
+    }
+}
+
+impl<E> From<E> for AnyEvent
+where
+    E: Debug + Event + 'static,
+{
+    fn from(event: E) -> Self {
+        Self::new(event)
+    }
+}
+
+// Erased
+
+trait Erased: Debug {
+    fn append(&self, events: &mut Events) -> Result<(), Error>;
+
+    fn as_any(&self) -> &dyn Any;
+}
+
+impl<E> Erased for E
+where
+    E: Debug + Event + 'static,
+{
+    fn append(&self, events: &mut Events) -> Result<(), Error> {
+        events.append(self)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
 // -------------------------------------------------------------------------------------------------
 
 // Events
 
-#[derive(new, Debug)]
+#[derive(new, Debug, Default)]
 pub struct Events {
     #[new(default)]
     events: Vec<CandidateEvent>,
@@ -69,7 +208,7 @@ impl Events {
         let data = revision::to_vec(event).map_err(|_| Error::data("serialization error"))?;
         let data = Data::new(data)?;
 
-        let identifier = E::identifier().cloned()?;
+        let identifier = event.identify().cloned()?;
         let tags = event.tags()?;
         let version = Version::default();
 
@@ -80,8 +219,65 @@ impl Events {
         Ok(())
     }
 
+    pub fn extend<I>(&mut self, events: I) -> Result<(), Error>
+    where
+        I: IntoIterator<Item = AnyEvent>,
+    {
+        for event in events {
+            event.event.append(self)?;
+        }
+
+        Ok(())
+    }
+
+    #[must_use]
+    pub fn candidates(&self) -> &[CandidateEvent] {
+        &self.events
+    }
+
+    pub fn merge(&mut self, other: Events) {
+        self.events.extend(other.events);
+    }
+
     #[must_use]
     pub fn take(self) -> Vec<CandidateEvent> {
         self.events
     }
 }
+
+// -------------------------------------------------------------------------------------------------
+
+// Functions
+
+static IDENTIFIERS: RwLock<BTreeMap<TypeId, &'static event::Identifier>> =
+    RwLock::new(BTreeMap::new());
+
+pub fn identifier<T, F, S>(name: F) -> Result<&'static event::Identifier, Error>
+where
+    T: ?Sized + 'static,
+    F: FnOnce() -> S,
+    S: AsRef<str>,
+{
+    let type_id = TypeId::of::<T>();
+
+    if let Some(identifier) = IDENTIFIERS
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get(&type_id)
+    {
+        return Ok(identifier);
+    }
+
+    let mut identifiers = IDENTIFIERS.write().unwrap_or_else(PoisonError::into_inner);
+
+    if let Some(identifier) = identifiers.get(&type_id) {
+        return Ok(identifier);
+    }
+
+    let identifier = event::Identifier::new(name().as_ref())?;
+    let identifier = Box::leak(Box::new(identifier));
+
+    identifiers.insert(type_id, identifier);
+
+    Ok(identifier)
+}
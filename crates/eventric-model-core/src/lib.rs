@@ -7,12 +7,18 @@
 #![allow(clippy::missing_safety_doc)]
 #![allow(missing_docs)]
 #![feature(associated_type_defaults)]
+#![feature(once_cell_try)]
 
 pub mod action;
+pub mod bus;
 pub mod core;
 pub mod error;
 pub mod event;
+pub mod outbox;
+pub mod process;
 pub mod projection;
+pub mod reaction;
+pub mod schedule;
 
 // =================================================================================================
 // Eventric Surface Core
@@ -0,0 +1,240 @@
+//! See the `eventric-surface` crate for full documentation, including
+//! module-level documentation.
+
+use std::{
+    error,
+    io::Write,
+    sync::{
+        OnceLock,
+        mpsc::Sender,
+    },
+};
+
+use eventric_stream::{
+    event::{
+        self,
+        Position,
+    },
+    stream::{
+        iterate::IterateSelect,
+        select::{
+            EventAndMask,
+            Selection,
+            Selections,
+            Selector,
+        },
+    },
+};
+use fancy_constructor::new;
+use revision::revisioned;
+
+use crate::{
+    error::{
+        Error,
+        OutboxError,
+    },
+    event::{
+        Event,
+        Identifier,
+        Specifiers,
+        Tags,
+    },
+    process::Checkpoint,
+};
+
+// =================================================================================================
+// Outbox
+// =================================================================================================
+
+// Outbox Message
+
+#[revisioned(revision = 1)]
+#[derive(new, Clone, Debug, Eq, PartialEq)]
+pub struct OutboxMessage {
+    #[new(into)]
+    pub topic: String,
+    #[new(into)]
+    pub payload: String,
+}
+
+impl Event for OutboxMessage {}
+
+impl Identifier for OutboxMessage {
+    fn identifier() -> Result<&'static event::Identifier, eventric_stream::error::Error> {
+        static IDENTIFIER: OnceLock<event::Identifier> = OnceLock::new();
+
+        IDENTIFIER.get_or_try_init(|| event::Identifier::new("eventric_outbox_message"))
+    }
+}
+
+impl Tags for OutboxMessage {
+    fn tags(&self) -> Result<Vec<event::Tag>, eventric_stream::error::Error> {
+        Ok(vec![event::tag!(topic, &self.topic)?])
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Relay
+
+#[derive(Debug)]
+pub struct Relay<K, C> {
+    pub sink: K,
+    checkpoint: C,
+    topics: Vec<String>,
+}
+
+impl<K, C> Relay<K, C>
+where
+    K: Sink,
+    C: Checkpoint,
+{
+    #[must_use]
+    pub fn new(sink: K, checkpoint: C) -> Self {
+        let topics = Vec::new();
+
+        Self {
+            sink,
+            checkpoint,
+            topics,
+        }
+    }
+
+    #[must_use]
+    pub fn topic<T>(mut self, topic: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.topics.push(topic.into());
+        self
+    }
+
+    pub fn run<S>(&mut self, stream: &S) -> Result<usize, Error>
+    where
+        S: IterateSelect,
+    {
+        let checkpoint = self.checkpoint.load()?;
+
+        let (events, _) = stream.iter_select(self.selections()?, checkpoint);
+
+        let mut delivered = 0;
+        let mut last = None;
+
+        for event in events {
+            let position = match self.deliver(event, checkpoint) {
+                Ok(Some(position)) => position,
+                Ok(None) => continue,
+                Err(err) => {
+                    if let Some(position) = last {
+                        self.checkpoint.save(position)?;
+                    }
+
+                    return Err(err);
+                }
+            };
+
+            last = Some(position);
+            delivered += 1;
+        }
+
+        if let Some(position) = last {
+            self.checkpoint.save(position)?;
+        }
+
+        Ok(delivered)
+    }
+
+    fn deliver(
+        &mut self,
+        event: Result<EventAndMask, eventric_stream::error::Error>,
+        checkpoint: Option<Position>,
+    ) -> Result<Option<Position>, Error> {
+        let event = event?.event;
+        let position = *event.position();
+
+        if checkpoint.is_some_and(|checkpoint| position <= checkpoint) {
+            return Ok(None);
+        }
+
+        let message = revision::from_slice::<OutboxMessage>(event.data().as_ref())
+            .map_err(|_| eventric_stream::error::Error::data("deserialization error"))?;
+
+        self.sink
+            .send(&message, position)
+            .map_err(OutboxError::Sink)?;
+
+        Ok(Some(position))
+    }
+
+    fn selections(&self) -> Result<Selections, Error> {
+        let specifiers = OutboxMessage::specifiers()?;
+
+        let selectors = if self.topics.is_empty() {
+            vec![Selector::specifiers(specifiers)?]
+        } else {
+            self.topics
+                .iter()
+                .map(|topic| {
+                    Selector::specifiers_and_tags(specifiers.clone(), [event::tag!(topic, topic)?])
+                })
+                .collect::<Result<Vec<_>, _>>()?
+        };
+
+        Ok(Selections::new([Selection::new(selectors)?])?)
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Sink
+
+pub trait Sink {
+    fn send(
+        &mut self,
+        message: &OutboxMessage,
+        position: Position,
+    ) -> Result<(), Box<dyn error::Error + Send + Sync>>;
+}
+
+// Channel Sink
+
+#[derive(new, Debug)]
+pub struct ChannelSink {
+    sender: Sender<OutboxMessage>,
+}
+
+impl Sink for ChannelSink {
+    fn send(
+        &mut self,
+        message: &OutboxMessage,
+        _: Position,
+    ) -> Result<(), Box<dyn error::Error + Send + Sync>> {
+        self.sender.send(message.clone())?;
+
+        Ok(())
+    }
+}
+
+// File Sink
+
+#[derive(new, Debug)]
+pub struct FileSink<W> {
+    writer: W,
+}
+
+impl<W> Sink for FileSink<W>
+where
+    W: Write,
+{
+    fn send(
+        &mut self,
+        message: &OutboxMessage,
+        _: Position,
+    ) -> Result<(), Box<dyn error::Error + Send + Sync>> {
+        writeln!(self.writer, "{}\t{}", message.topic, message.payload)?;
+
+        self.writer.flush()?;
+
+        Ok(())
+    }
+}
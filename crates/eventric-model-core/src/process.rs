@@ -0,0 +1,194 @@
+//! See the `eventric-surface` crate for full documentation, including
+//! module-level documentation.
+
+use std::{
+    fs,
+    io::ErrorKind,
+    iter,
+    path::PathBuf,
+};
+
+use eventric_stream::{
+    event::Position,
+    stream::{
+        iterate::IterateSelect,
+        select::Selections,
+    },
+};
+use fancy_constructor::new;
+
+use crate::{
+    action::{
+        Act,
+        Action,
+        Context,
+    },
+    core::Enactor,
+    error::{
+        CheckpointError,
+        Error,
+    },
+    projection::Projection,
+};
+
+// =================================================================================================
+// Process
+// =================================================================================================
+
+// Process Manager
+
+pub trait ProcessManager: Projection {
+    type Action: Action;
+
+    fn actions(&mut self) -> Vec<Self::Action>;
+}
+
+// Process
+
+type Outcome<P> =
+    Result<<<P as ProcessManager>::Action as Act>::Ok, <<P as ProcessManager>::Action as Act>::Err>;
+
+#[derive(Debug)]
+pub struct Process<P, C> {
+    pub manager: P,
+    checkpoint: C,
+}
+
+impl<P, C> Process<P, C>
+where
+    P: ProcessManager,
+    C: Checkpoint,
+{
+    #[must_use]
+    pub fn new(manager: P, checkpoint: C) -> Self {
+        Self {
+            manager,
+            checkpoint,
+        }
+    }
+
+    pub fn run<S>(&mut self, stream: &mut S) -> Result<Vec<Outcome<P>>, Error>
+    where
+        S: Enactor + IterateSelect,
+        P: Clone,
+        P::Action: Context<Deps = ()>,
+    {
+        let checkpoint = self.checkpoint.load()?;
+        let mut manager = self.manager.clone();
+        let selections = Selections::new(iter::once(manager.select()?))?;
+        let selectors = manager.selectors()?;
+
+        let (events, _) = stream.iter_select(selections, None);
+
+        let mut reactions = Vec::new();
+
+        for event in events {
+            let event_and_mask = event?;
+            let position = *event_and_mask.event.position();
+
+            if let Some(dispatch_event) = manager.recognize(&event_and_mask)? {
+                let selected = selectors.selected(&dispatch_event);
+
+                manager.dispatch(&dispatch_event, &selected)?;
+            }
+
+            let actions = manager.actions();
+
+            if !actions.is_empty() && checkpoint.is_none_or(|checkpoint| position > checkpoint) {
+                reactions.push((position, actions));
+            }
+        }
+
+        let mut outcomes = Vec::new();
+
+        for (position, actions) in reactions {
+            let enacted = actions
+                .into_iter()
+                .map(|action| stream.enact(action))
+                .collect::<Vec<_>>();
+
+            let failed = enacted.iter().any(Result::is_err);
+
+            outcomes.extend(enacted);
+
+            if failed {
+                break;
+            }
+
+            self.checkpoint.save(position)?;
+        }
+
+        Ok(outcomes)
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Checkpoint
+
+pub trait Checkpoint {
+    fn load(&mut self) -> Result<Option<Position>, Error>;
+
+    fn save(&mut self, position: Position) -> Result<(), Error>;
+}
+
+// Memory Checkpoint
+
+#[derive(Debug, Default)]
+pub struct MemoryCheckpoint {
+    pub position: Option<Position>,
+}
+
+impl MemoryCheckpoint {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Checkpoint for MemoryCheckpoint {
+    fn load(&mut self) -> Result<Option<Position>, Error> {
+        Ok(self.position)
+    }
+
+    fn save(&mut self, position: Position) -> Result<(), Error> {
+        self.position = Some(position);
+
+        Ok(())
+    }
+}
+
+// File Checkpoint
+
+#[derive(new, Debug)]
+pub struct FileCheckpoint {
+    #[new(into)]
+    pub path: PathBuf,
+}
+
+impl Checkpoint for FileCheckpoint {
+    fn load(&mut self) -> Result<Option<Position>, Error> {
+        let contents = match fs::read_to_string(&self.path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(CheckpointError::Io(err).into()),
+        };
+
+        let position = contents
+            .trim()
+            .parse()
+            .map_err(|_| CheckpointError::Invalid(contents.clone()))?;
+
+        Ok(Some(Position::new(position)))
+    }
+
+    fn save(&mut self, position: Position) -> Result<(), Error> {
+        let path = self.path.with_extension("tmp");
+
+        fs::write(&path, (*position).to_string())
+            .and_then(|()| fs::rename(&path, &self.path))
+            .map_err(CheckpointError::Io)?;
+
+        Ok(())
+    }
+}
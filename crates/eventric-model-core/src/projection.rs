@@ -1,12 +1,25 @@
 //! See the `eventric-surface` crate for full documentation, including
 //! module-level documentation.
 
-use std::any::Any;
+pub mod library;
+
+use std::{
+    any::{
+        self,
+        Any,
+    },
+    convert::Infallible,
+    error,
+    fmt::Debug,
+};
 
 use derive_more::Deref;
 use eventric_stream::{
     error::Error,
-    event,
+    event::{
+        self,
+        CandidateEvent,
+    },
     stream::select::{
         EventAndMask,
         Selection,
@@ -14,7 +27,10 @@ use eventric_stream::{
 };
 use fancy_constructor::new;
 
-use crate::event::Event;
+use crate::{
+    error::ProjectionError,
+    event::Event,
+};
 
 // =================================================================================================
 // Projection
@@ -27,28 +43,155 @@ pub trait Projection: Dispatch + Recognize + Select {}
 // Dispatch
 
 pub trait Dispatch {
-    fn dispatch(&mut self, event: &DispatchEvent);
+    fn dispatch(
+        &mut self,
+        event: &DispatchEvent,
+        selected: &Selected,
+    ) -> Result<(), crate::error::Error>;
+
+    fn dispatch_candidate(
+        &mut self,
+        _: &DispatchEvent<Candidate>,
+        _: &Selected,
+    ) -> Result<(), crate::error::Error> {
+        Err(crate::error::Error::Candidate(any::type_name::<Self>()))
+    }
 }
 
 // Project
 
 pub trait Project<E>
+where
+    E: Event,
+    Self::Err: error::Error + Send + Sync + 'static,
+{
+    type Err = Infallible;
+
+    fn project(&mut self, event: ProjectionEvent<'_, E>) -> Result<(), Self::Err>;
+}
+
+// Project Candidate
+
+pub trait ProjectCandidate<E>: Project<E>
 where
     E: Event,
 {
-    fn project(&mut self, event: ProjectionEvent<'_, E>);
+    fn project_candidate(
+        &mut self,
+        event: ProjectionEvent<'_, E, Candidate>,
+    ) -> Result<(), Self::Err>;
 }
 
 // Recognize
 
 pub trait Recognize {
+    const DECODE: Decode = Decode::Fail;
+
     fn recognize(&self, event: &EventAndMask) -> Result<Option<DispatchEvent>, Error>;
+
+    fn recognize_candidate(
+        &self,
+        _: &CandidateEvent,
+    ) -> Result<Option<DispatchEvent<Candidate>>, crate::error::Error> {
+        Err(crate::error::Error::Candidate(any::type_name::<Self>()))
+    }
 }
 
 // Select
 
 pub trait Select {
     fn select(&self) -> Result<Selection, Error>;
+
+    fn selectors(&self) -> Result<Selectors, Error> {
+        Ok(Selectors::any())
+    }
+
+    fn selected<O>(&self, event: &DispatchEvent<O>) -> Result<Selected, Error>
+    where
+        Self: Sized,
+        O: Origin,
+    {
+        self.selectors().map(|selectors| selectors.selected(event))
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Decode
+
+#[derive(Clone, Copy, Debug, Default)]
+pub enum Decode {
+    DeadLetter(fn(Undecoded<'_>, &Error)),
+    #[default]
+    Fail,
+    Skip,
+}
+
+impl Decode {
+    pub fn recover<O>(
+        self,
+        event: Undecoded<'_>,
+        error: Error,
+    ) -> Result<Option<DispatchEvent<O>>, Error> {
+        match self {
+            Self::DeadLetter(dead_letter) => {
+                dead_letter(event, &error);
+
+                Ok(None)
+            }
+            Self::Fail => Err(error),
+            Self::Skip => {
+                log::warn!(
+                    "skipping undecodable event {:?}: {error}",
+                    event.identifier()
+                );
+
+                Ok(None)
+            }
+        }
+    }
+}
+
+// Undecoded
+
+#[derive(Clone, Copy, Debug)]
+pub enum Undecoded<'a> {
+    Candidate(&'a CandidateEvent),
+    Stored(&'a event::Event),
+}
+
+impl Undecoded<'_> {
+    #[must_use]
+    pub fn data(&self) -> &event::Data {
+        match self {
+            Self::Candidate(event) => event.data(),
+            Self::Stored(event) => event.data(),
+        }
+    }
+
+    #[must_use]
+    pub fn identifier(&self) -> &event::Identifier {
+        match self {
+            Self::Candidate(event) => event.identifier(),
+            Self::Stored(event) => event.identifier(),
+        }
+    }
+
+    #[must_use]
+    pub fn position(&self) -> Option<event::Position> {
+        match self {
+            Self::Candidate(_) => None,
+            Self::Stored(event) => Some(*event.position()),
+        }
+    }
+
+    #[must_use]
+    pub fn tags(&self) -> &[event::Tag] {
+        match self {
+            Self::Candidate(event) => event.tags(),
+            Self::Stored(event) => event.tags(),
+        }
+    }
 }
 
 // -------------------------------------------------------------------------------------------------
@@ -57,39 +200,238 @@ pub trait Select {
 
 #[derive(new, Debug)]
 #[new(const_fn, vis(pub(crate)))]
-pub struct DispatchEvent {
+pub struct DispatchEvent<O = Stored> {
     pub event: Box<dyn Any>,
     pub identifier: event::Identifier,
-    pub position: event::Position,
-    pub timestamp: event::Timestamp,
+    pub origin: O,
+    pub tags: Vec<event::Tag>,
+    pub version: event::Version,
 }
 
-impl DispatchEvent {
+impl<O> DispatchEvent<O>
+where
+    O: Origin,
+{
     #[must_use]
-    pub fn as_projection_event<E>(&self) -> Option<ProjectionEvent<'_, E>>
+    pub fn as_projection_event<'a, E>(
+        &'a self,
+        selected: &'a Selected,
+    ) -> Option<ProjectionEvent<'a, E, O>>
     where
         E: Event + 'static,
     {
-        self.event
-            .downcast_ref()
-            .map(|inner_event| ProjectionEvent::new(inner_event, self.position, self.timestamp))
+        self.event.downcast_ref().map(|inner_event| {
+            ProjectionEvent::new(
+                inner_event,
+                &self.identifier,
+                self.origin,
+                selected,
+                &self.tags,
+                self.version,
+            )
+        })
+    }
+
+    pub fn projection_error<P, Err>(&self, source: Err) -> crate::error::Error
+    where
+        Err: error::Error + Send + Sync + 'static,
+    {
+        let identifier = self.identifier.clone();
+        let position = self.origin.position();
+        let projection = any::type_name::<P>();
+        let source = Box::new(source);
+
+        ProjectionError::new(identifier, position, projection, source).into()
     }
+}
 
+impl DispatchEvent {
     pub fn from_event<E>(event: &event::Event) -> Result<Self, Error>
     where
         E: Event + 'static,
     {
-        revision::from_slice::<E>(event.data().as_ref())
-            .map_err(|_| Error::data("deserialization error"))
-            .map(|inner_event| Box::new(inner_event) as Box<dyn Any>)
-            .map(|inner_event| {
-                Self::new(
-                    inner_event,
-                    event.identifier().clone(),
-                    *event.position(),
-                    *event.timestamp(),
-                )
-            })
+        decode::<E>(event.data()).map(|inner_event| {
+            Self::new(
+                inner_event,
+                event.identifier().clone(),
+                Stored::new(*event.position(), *event.timestamp()),
+                event.tags().to_vec(),
+                *event.version(),
+            )
+        })
+    }
+}
+
+impl DispatchEvent<Candidate> {
+    pub fn from_candidate<E>(event: &CandidateEvent) -> Result<Self, Error>
+    where
+        E: Event + 'static,
+    {
+        decode::<E>(event.data()).map(|inner_event| {
+            Self::new(
+                inner_event,
+                event.identifier().clone(),
+                Candidate,
+                event.tags().to_vec(),
+                *event.version(),
+            )
+        })
+    }
+}
+
+fn decode<E>(data: &event::Data) -> Result<Box<dyn Any>, Error>
+where
+    E: Event + 'static,
+{
+    revision::from_slice::<E>(data.as_ref())
+        .map_err(|_| Error::data("deserialization error"))
+        .map(|inner_event| Box::new(inner_event) as Box<dyn Any>)
+}
+
+// Origin
+
+pub trait Origin: Copy + Debug {
+    fn position(&self) -> Option<event::Position>;
+}
+
+// Candidate
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Candidate;
+
+impl Origin for Candidate {
+    fn position(&self) -> Option<event::Position> {
+        None
+    }
+}
+
+// Stored
+
+#[derive(new, Clone, Copy, Debug)]
+#[new(const_fn, vis(pub(crate)))]
+pub struct Stored {
+    pub position: event::Position,
+    pub timestamp: event::Timestamp,
+}
+
+impl Origin for Stored {
+    fn position(&self) -> Option<event::Position> {
+        Some(self.position)
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Selected
+
+#[derive(new, Debug)]
+pub struct Selected {
+    mask: Vec<bool>,
+    names: &'static [Option<&'static str>],
+}
+
+impl Selected {
+    #[must_use]
+    pub fn index(&self) -> Option<usize> {
+        self.indices().next()
+    }
+
+    pub fn indices(&self) -> impl Iterator<Item = usize> + '_ {
+        self.mask
+            .iter()
+            .enumerate()
+            .filter_map(|(index, selected)| selected.then_some(index))
+    }
+
+    #[must_use]
+    pub fn is(&self, index: usize) -> bool {
+        self.mask.get(index).copied().unwrap_or_default()
+    }
+
+    #[must_use]
+    pub fn is_named(&self, name: &str) -> bool {
+        self.names().any(|selected| selected == name)
+    }
+
+    #[must_use]
+    pub fn name(&self) -> Option<&'static str> {
+        self.names().next()
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.indices()
+            .filter_map(|index| self.names.get(index).copied().flatten())
+    }
+}
+
+// Selectors
+
+type SelectorFilter = (Option<Vec<&'static event::Identifier>>, Vec<event::Tag>);
+
+#[derive(Debug)]
+pub struct Selectors {
+    names: &'static [Option<&'static str>],
+    selectors: Vec<SelectorFilter>,
+}
+
+impl Selectors {
+    #[must_use]
+    pub fn new(names: &'static [Option<&'static str>]) -> Self {
+        let selectors = Vec::new();
+
+        Self { names, selectors }
+    }
+
+    #[must_use]
+    pub fn any() -> Self {
+        let names = &[None];
+        let selectors = vec![(None, Vec::new())];
+
+        Self { names, selectors }
+    }
+
+    #[must_use]
+    pub fn selector<I, T>(mut self, identifiers: I, tags: T) -> Self
+    where
+        I: IntoIterator<Item = &'static event::Identifier>,
+        T: IntoIterator<Item = event::Tag>,
+    {
+        self.selectors.push((
+            Some(identifiers.into_iter().collect()),
+            tags.into_iter().collect(),
+        ));
+        self
+    }
+
+    #[must_use]
+    pub fn matches(&self, identifier: &event::Identifier, tags: &[event::Tag]) -> bool {
+        self.selectors
+            .iter()
+            .any(|selector| Self::matches_selector(selector, identifier, tags))
+    }
+
+    #[must_use]
+    pub fn selected<O>(&self, event: &DispatchEvent<O>) -> Selected {
+        let mask = match self.selectors.as_slice() {
+            [_] => vec![true],
+            selectors => selectors
+                .iter()
+                .map(|selector| Self::matches_selector(selector, &event.identifier, &event.tags))
+                .collect(),
+        };
+
+        Selected::new(mask, self.names)
+    }
+
+    fn matches_selector(
+        (identifiers, filter): &SelectorFilter,
+        identifier: &event::Identifier,
+        tags: &[event::Tag],
+    ) -> bool {
+        identifiers
+            .as_ref()
+            .is_none_or(|identifiers| identifiers.contains(&identifier))
+            && filter.iter().all(|tag| tags.contains(tag))
     }
 }
 
@@ -99,14 +441,47 @@ impl DispatchEvent {
 
 #[derive(new, Debug, Deref)]
 #[new(const_fn, vis(pub(crate)))]
-pub struct ProjectionEvent<'a, E>
+pub struct ProjectionEvent<'a, E, O = Stored>
 where
     E: Event,
 {
     #[deref]
     event: &'a E,
-    position: event::Position,
-    timestamp: event::Timestamp,
+    identifier: &'a event::Identifier,
+    origin: O,
+    selected: &'a Selected,
+    tags: &'a [event::Tag],
+    version: event::Version,
+}
+
+impl<E, O> ProjectionEvent<'_, E, O>
+where
+    E: Event,
+{
+    #[must_use]
+    pub fn identifier(&self) -> &event::Identifier {
+        self.identifier
+    }
+
+    #[must_use]
+    pub fn origin(&self) -> &O {
+        &self.origin
+    }
+
+    #[must_use]
+    pub fn selected(&self) -> &Selected {
+        self.selected
+    }
+
+    #[must_use]
+    pub fn tags(&self) -> &[event::Tag] {
+        self.tags
+    }
+
+    #[must_use]
+    pub fn version(&self) -> &event::Version {
+        &self.version
+    }
 }
 
 impl<E> ProjectionEvent<'_, E>
@@ -115,11 +490,11 @@ where
 {
     #[must_use]
     pub fn position(&self) -> &event::Position {
-        &self.position
+        &self.origin.position
     }
 
     #[must_use]
     pub fn timestamp(&self) -> &event::Timestamp {
-        &self.timestamp
+        &self.origin.timestamp
     }
 }
@@ -0,0 +1,633 @@
+//! See the `eventric-surface` crate for full documentation, including
+//! module-level documentation.
+
+use std::{
+    collections::{
+        BTreeMap,
+        btree_map::Entry,
+    },
+    fmt::{
+        self,
+        Debug,
+        Formatter,
+    },
+    marker::PhantomData,
+};
+
+use eventric_stream::{
+    error::Error,
+    event::{
+        self,
+        CandidateEvent,
+    },
+    stream::select::{
+        EventAndMask,
+        Selection,
+        Selector,
+    },
+};
+use thiserror::Error;
+
+use crate::{
+    event::{
+        Event,
+        Specifiers,
+    },
+    projection::{
+        Candidate,
+        Decode,
+        Dispatch,
+        DispatchEvent,
+        Origin,
+        Projection,
+        ProjectionEvent,
+        Recognize,
+        Select,
+        Selected,
+        Selectors,
+        Undecoded,
+    },
+};
+
+// =================================================================================================
+// Library
+// =================================================================================================
+
+// Helpers
+
+macro_rules! impl_filtered {
+    ($projection:ident<$($param:ident),+>) => {
+        impl<$($param),+> Dispatch for $projection<$($param),+>
+        where
+            Self: Fold,
+        {
+            fn dispatch(
+                &mut self,
+                event: &DispatchEvent,
+                selected: &Selected,
+            ) -> Result<(), crate::error::Error> {
+                self.fold(event, selected)
+            }
+
+            fn dispatch_candidate(
+                &mut self,
+                event: &DispatchEvent<Candidate>,
+                selected: &Selected,
+            ) -> Result<(), crate::error::Error> {
+                self.fold(event, selected)
+            }
+        }
+
+        impl<$($param),+> Projection for $projection<$($param),+>
+        where
+            Self: Dispatch,
+            E: Event + 'static,
+        {
+        }
+
+        impl<$($param),+> Recognize for $projection<$($param),+>
+        where
+            E: Event + 'static,
+        {
+            fn recognize(&self, event: &EventAndMask) -> Result<Option<DispatchEvent>, Error> {
+                Filter::<E>::recognize(event, Self::DECODE)
+            }
+
+            fn recognize_candidate(
+                &self,
+                event: &CandidateEvent,
+            ) -> Result<Option<DispatchEvent<Candidate>>, crate::error::Error> {
+                Ok(Filter::<E>::recognize_candidate(event, Self::DECODE)?)
+            }
+        }
+
+        impl<$($param),+> Select for $projection<$($param),+>
+        where
+            E: Event + 'static,
+        {
+            fn select(&self) -> Result<Selection, Error> {
+                self.filter.select()
+            }
+
+            fn selectors(&self) -> Result<Selectors, Error> {
+                self.filter.selectors()
+            }
+        }
+    };
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Count
+
+#[derive(Debug)]
+pub struct Count<E> {
+    pub count: u64,
+    filter: Filter<E>,
+}
+
+impl<E> Count<E> {
+    #[must_use]
+    pub fn new<T>(tags: T) -> Self
+    where
+        T: IntoIterator<Item = event::Tag>,
+    {
+        let count = 0;
+        let filter = Filter::new(tags);
+
+        Self { count, filter }
+    }
+}
+
+impl<E> Fold for Count<E>
+where
+    E: Event + 'static,
+{
+    fn fold<O>(
+        &mut self,
+        event: &DispatchEvent<O>,
+        selected: &Selected,
+    ) -> Result<(), crate::error::Error>
+    where
+        O: Origin,
+    {
+        if event.as_projection_event::<E>(selected).is_some() {
+            self.count = self
+                .count
+                .checked_add(1)
+                .ok_or_else(|| event.projection_error::<Self, _>(Overflow))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl_filtered!(Count<E>);
+
+// Distinct Tags
+
+#[derive(Debug)]
+pub struct DistinctTags<E> {
+    pub tags: Vec<event::Tag>,
+    filter: Filter<E>,
+}
+
+impl<E> DistinctTags<E> {
+    #[must_use]
+    pub fn new<T>(tags: T) -> Self
+    where
+        T: IntoIterator<Item = event::Tag>,
+    {
+        let filter = Filter::new(tags);
+        let tags = Vec::new();
+
+        Self { tags, filter }
+    }
+}
+
+impl<E> Fold for DistinctTags<E>
+where
+    E: Event + 'static,
+{
+    fn fold<O>(
+        &mut self,
+        event: &DispatchEvent<O>,
+        selected: &Selected,
+    ) -> Result<(), crate::error::Error>
+    where
+        O: Origin,
+    {
+        if let Some(event) = event.as_projection_event::<E>(selected) {
+            for tag in event.tags() {
+                if !self.tags.contains(tag) {
+                    self.tags.push(tag.clone());
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl_filtered!(DistinctTags<E>);
+
+// Exists
+
+#[derive(Debug)]
+pub struct Exists<E> {
+    pub exists: bool,
+    filter: Filter<E>,
+}
+
+impl<E> Exists<E> {
+    #[must_use]
+    pub fn new<T>(tags: T) -> Self
+    where
+        T: IntoIterator<Item = event::Tag>,
+    {
+        let exists = false;
+        let filter = Filter::new(tags);
+
+        Self { exists, filter }
+    }
+}
+
+impl<E> Fold for Exists<E>
+where
+    E: Event + 'static,
+{
+    fn fold<O>(
+        &mut self,
+        event: &DispatchEvent<O>,
+        selected: &Selected,
+    ) -> Result<(), crate::error::Error>
+    where
+        O: Origin,
+    {
+        if event.as_projection_event::<E>(selected).is_some() {
+            self.exists = true;
+        }
+
+        Ok(())
+    }
+}
+
+impl_filtered!(Exists<E>);
+
+// Keyed
+
+#[derive(Debug)]
+pub struct Keyed<K, P> {
+    pub states: BTreeMap<K, P>,
+    init: fn(&K) -> P,
+    keys: Vec<Key<K>>,
+}
+
+impl<K, P> Keyed<K, P> {
+    #[must_use]
+    pub fn new(init: fn(&K) -> P) -> Self {
+        let states = BTreeMap::new();
+        let keys = Vec::new();
+
+        Self { states, init, keys }
+    }
+
+    #[must_use]
+    pub fn key<E>(mut self, key: fn(&ProjectionEvent<'_, E>) -> Option<K>) -> Self
+    where
+        E: Event + 'static,
+        K: 'static,
+    {
+        self.keys.push(Key::new(key));
+        self
+    }
+}
+
+impl<K, P> Dispatch for Keyed<K, P>
+where
+    K: Ord,
+    P: Projection,
+{
+    fn dispatch(
+        &mut self,
+        event: &DispatchEvent,
+        selected: &Selected,
+    ) -> Result<(), crate::error::Error> {
+        let Some(key) = self
+            .keys
+            .iter()
+            .find_map(|key| (key.extract)(event, selected))
+        else {
+            return Ok(());
+        };
+
+        match self.states.entry(key) {
+            Entry::Occupied(mut entry) => {
+                let selectors = entry.get().selectors()?;
+
+                if selectors.matches(&event.identifier, &event.tags) {
+                    entry
+                        .get_mut()
+                        .dispatch(event, &selectors.selected(event))?;
+                }
+            }
+            Entry::Vacant(entry) => {
+                let mut state = (self.init)(entry.key());
+                let selectors = state.selectors()?;
+
+                if selectors.matches(&event.identifier, &event.tags) {
+                    state.dispatch(event, &selectors.selected(event))?;
+
+                    entry.insert(state);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<K, P> Projection for Keyed<K, P>
+where
+    K: Ord,
+    P: Projection,
+{
+}
+
+impl<K, P> Recognize for Keyed<K, P>
+where
+    P: Recognize,
+{
+    fn recognize(&self, event: &EventAndMask) -> Result<Option<DispatchEvent>, Error> {
+        for key in &self.keys {
+            if (key.identifiers)()?.contains(&event.event.identifier()) {
+                return match (key.decode)(&event.event) {
+                    Ok(dispatch_event) => Ok(Some(dispatch_event)),
+                    Err(err) => P::DECODE.recover(Undecoded::Stored(&event.event), err),
+                };
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+impl<K, P> Select for Keyed<K, P> {
+    fn select(&self) -> Result<Selection, Error> {
+        let mut specifiers = Vec::new();
+
+        for key in &self.keys {
+            specifiers.extend((key.specifiers)()?);
+        }
+
+        Selection::new([Selector::specifiers(specifiers)?])
+    }
+
+    fn selectors(&self) -> Result<Selectors, Error> {
+        let mut identifiers = Vec::new();
+
+        for key in &self.keys {
+            identifiers.extend((key.identifiers)()?);
+        }
+
+        Ok(Selectors::new(&[None]).selector(identifiers, []))
+    }
+}
+
+// Key
+
+type KeyFn<K> = Box<dyn Fn(&DispatchEvent, &Selected) -> Option<K> + Send + Sync>;
+
+struct Key<K> {
+    decode: fn(&event::Event) -> Result<DispatchEvent, Error>,
+    extract: KeyFn<K>,
+    identifiers: fn() -> Result<Vec<&'static event::Identifier>, Error>,
+    specifiers: fn() -> Result<Vec<event::Specifier>, Error>,
+}
+
+impl<K> Key<K> {
+    fn new<E>(key: fn(&ProjectionEvent<'_, E>) -> Option<K>) -> Self
+    where
+        E: Event + 'static,
+        K: 'static,
+    {
+        Self {
+            decode: DispatchEvent::from_event::<E>,
+            extract: Box::new(move |event, selected| {
+                event
+                    .as_projection_event::<E>(selected)
+                    .and_then(|event| key(&event))
+            }),
+            identifiers: E::identifiers,
+            specifiers: E::specifiers,
+        }
+    }
+}
+
+impl<K> Debug for Key<K> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Key").finish_non_exhaustive()
+    }
+}
+
+// Last
+
+#[derive(Debug)]
+pub struct Last<E> {
+    pub last: Option<E>,
+    pub position: Option<event::Position>,
+    filter: Filter<E>,
+}
+
+impl<E> Last<E> {
+    #[must_use]
+    pub fn new<T>(tags: T) -> Self
+    where
+        T: IntoIterator<Item = event::Tag>,
+    {
+        let filter = Filter::new(tags);
+
+        Self {
+            last: None,
+            position: None,
+            filter,
+        }
+    }
+}
+
+impl<E> Fold for Last<E>
+where
+    E: Clone + Event + 'static,
+{
+    fn fold<O>(
+        &mut self,
+        event: &DispatchEvent<O>,
+        selected: &Selected,
+    ) -> Result<(), crate::error::Error>
+    where
+        O: Origin,
+    {
+        if let Some(event) = event.as_projection_event::<E>(selected) {
+            self.last = Some((*event).clone());
+            self.position = event.origin().position();
+        }
+
+        Ok(())
+    }
+}
+
+impl_filtered!(Last<E>);
+
+// Sum
+
+#[derive(Debug)]
+pub struct Sum<E, F> {
+    pub sum: F,
+    filter: Filter<E>,
+    value: fn(&E) -> F,
+}
+
+impl<E, F> Sum<E, F>
+where
+    F: Default,
+{
+    #[must_use]
+    pub fn new<T>(tags: T, value: fn(&E) -> F) -> Self
+    where
+        T: IntoIterator<Item = event::Tag>,
+    {
+        let filter = Filter::new(tags);
+        let sum = F::default();
+
+        Self { sum, filter, value }
+    }
+}
+
+impl<E, F> Fold for Sum<E, F>
+where
+    E: Event + 'static,
+    F: Summable,
+{
+    fn fold<O>(
+        &mut self,
+        event: &DispatchEvent<O>,
+        selected: &Selected,
+    ) -> Result<(), crate::error::Error>
+    where
+        O: Origin,
+    {
+        if let Some(projection_event) = event.as_projection_event::<E>(selected) {
+            self.sum = self
+                .sum
+                .checked_sum((self.value)(&projection_event))
+                .ok_or_else(|| event.projection_error::<Self, _>(Overflow))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl_filtered!(Sum<E, F>);
+
+// -------------------------------------------------------------------------------------------------
+
+// Fold
+
+trait Fold {
+    fn fold<O>(
+        &mut self,
+        event: &DispatchEvent<O>,
+        selected: &Selected,
+    ) -> Result<(), crate::error::Error>
+    where
+        O: Origin;
+}
+
+// Filter
+
+#[derive(Debug)]
+struct Filter<E> {
+    event: PhantomData<fn() -> E>,
+    tags: Vec<event::Tag>,
+}
+
+impl<E> Filter<E> {
+    fn new<T>(tags: T) -> Self
+    where
+        T: IntoIterator<Item = event::Tag>,
+    {
+        let event = PhantomData;
+        let tags = tags.into_iter().collect();
+
+        Self { event, tags }
+    }
+}
+
+impl<E> Filter<E>
+where
+    E: Event + 'static,
+{
+    fn recognize(event: &EventAndMask, decode: Decode) -> Result<Option<DispatchEvent>, Error> {
+        if E::identifiers()?.contains(&event.event.identifier()) {
+            return match DispatchEvent::from_event::<E>(&event.event) {
+                Ok(dispatch_event) => Ok(Some(dispatch_event)),
+                Err(err) => decode.recover(Undecoded::Stored(&event.event), err),
+            };
+        }
+
+        Ok(None)
+    }
+
+    fn recognize_candidate(
+        event: &CandidateEvent,
+        decode: Decode,
+    ) -> Result<Option<DispatchEvent<Candidate>>, Error> {
+        if E::identifiers()?.contains(&event.identifier()) {
+            return match DispatchEvent::from_candidate::<E>(event) {
+                Ok(dispatch_event) => Ok(Some(dispatch_event)),
+                Err(err) => decode.recover(Undecoded::Candidate(event), err),
+            };
+        }
+
+        Ok(None)
+    }
+
+    fn select(&self) -> Result<Selection, Error> {
+        let specifiers = E::specifiers()?;
+
+        let selector = if self.tags.is_empty() {
+            Selector::specifiers(specifiers)?
+        } else {
+            Selector::specifiers_and_tags(specifiers, self.tags.clone())?
+        };
+
+        Selection::new([selector])
+    }
+
+    fn selectors(&self) -> Result<Selectors, Error> {
+        Ok(Selectors::new(&[None]).selector(E::identifiers()?, self.tags.clone()))
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Overflow
+
+#[derive(Debug, Error)]
+#[error("overflow")]
+pub struct Overflow;
+
+// Summable
+
+pub trait Summable: Copy {
+    fn checked_sum(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! impl_summable_checked {
+    ($($type:ty),*) => {
+        $(
+            impl Summable for $type {
+                fn checked_sum(self, other: Self) -> Option<Self> {
+                    self.checked_add(other)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_summable_float {
+    ($($type:ty),*) => {
+        $(
+            impl Summable for $type {
+                fn checked_sum(self, other: Self) -> Option<Self> {
+                    Some(self + other).filter(|sum| sum.is_finite())
+                }
+            }
+        )*
+    };
+}
+
+impl_summable_checked!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+impl_summable_float!(f32, f64);
@@ -0,0 +1,285 @@
+//! See the `eventric-surface` crate for full documentation, including
+//! module-level documentation.
+
+use std::{
+    collections::VecDeque,
+    fmt::{
+        self,
+        Debug,
+        Formatter,
+    },
+    sync::mpsc::Sender,
+};
+
+use derive_more::Deref;
+use eventric_stream::{
+    error::Error,
+    event::{
+        self,
+        CandidateEvent,
+    },
+    stream::{
+        Select,
+        append::{
+            Append,
+            AppendSelect,
+        },
+        iterate::IterateSelect,
+        select::{
+            EventAndMask,
+            Selections,
+        },
+    },
+};
+
+use crate::{
+    action::{
+        Action,
+        Context,
+    },
+    core::{
+        self,
+        Enactor,
+    },
+    error::ReactionError,
+    event::Event,
+};
+
+// =================================================================================================
+// Reaction
+// =================================================================================================
+
+// Reacting
+
+#[derive(Debug)]
+pub struct Reacting<S> {
+    pub stream: S,
+    errors: Errors,
+    reactors: Vec<Reactor>,
+}
+
+impl<S> Reacting<S> {
+    #[must_use]
+    pub fn new(stream: S) -> Self {
+        let errors = Errors::new(ERRORS_CAPACITY);
+        let reactors = Vec::new();
+
+        Self {
+            stream,
+            errors,
+            reactors,
+        }
+    }
+
+    #[must_use]
+    pub fn error_capacity(mut self, capacity: usize) -> Self {
+        self.errors.capacity = capacity;
+        self.errors.truncate();
+        self
+    }
+
+    pub fn take_errors(&mut self) -> Vec<ReactionError> {
+        self.errors.errors.drain(..).collect()
+    }
+
+    #[must_use]
+    pub fn on<E, F>(mut self, handler: F) -> Self
+    where
+        E: Event + 'static,
+        F: FnMut(Reaction<E>) + Send + 'static,
+    {
+        self.reactors.push(Reactor::new(handler));
+        self
+    }
+
+    #[must_use]
+    pub fn queue<E>(self, sender: Sender<Reaction<E>>) -> Self
+    where
+        E: Event + Send + 'static,
+    {
+        self.on(move |reaction| {
+            sender.send(reaction).ok();
+        })
+    }
+}
+
+impl<S> Enactor for Reacting<S>
+where
+    S: Append + AppendSelect + IterateSelect,
+{
+    fn enact_batch<A, I>(&mut self, actions: I) -> Vec<Result<A::Ok, A::Err>>
+    where
+        A: Action + Context<Deps = ()>,
+        I: IntoIterator<Item = A>,
+    {
+        let Self {
+            stream,
+            errors,
+            reactors,
+        } = self;
+
+        core::enact_batch(
+            stream,
+            actions,
+            Some(&mut |event, position| react(reactors, errors, event, position)),
+        )
+    }
+
+    fn try_enact_with<A>(
+        &mut self,
+        action: A,
+        deps: A::Deps,
+    ) -> Result<Result<A::Ok, A::Err>, crate::error::Error>
+    where
+        A: Action,
+    {
+        let Self {
+            stream,
+            errors,
+            reactors,
+        } = self;
+
+        core::try_enact(
+            stream,
+            action,
+            deps,
+            Some(&mut |event, position| react(reactors, errors, event, position)),
+        )
+    }
+}
+
+impl<S> IterateSelect for Reacting<S>
+where
+    S: IterateSelect,
+{
+    fn iter_select(
+        &self,
+        selections: Selections,
+        from: Option<event::Position>,
+    ) -> (impl Iterator<Item = Result<EventAndMask, Error>>, Select) {
+        self.stream.iter_select(selections, from)
+    }
+}
+
+fn react(
+    reactors: &mut [Reactor],
+    errors: &mut Errors,
+    event: &CandidateEvent,
+    position: event::Position,
+) {
+    for reactor in reactors {
+        if let Err(err) = (reactor.react)(event, position) {
+            errors.push(ReactionError::new(
+                event.identifier().clone(),
+                position,
+                err,
+            ));
+        }
+    }
+}
+
+// Errors
+
+const ERRORS_CAPACITY: usize = 64;
+
+#[derive(Debug)]
+struct Errors {
+    capacity: usize,
+    errors: VecDeque<ReactionError>,
+}
+
+impl Errors {
+    fn new(capacity: usize) -> Self {
+        let errors = VecDeque::new();
+
+        Self { capacity, errors }
+    }
+
+    fn push(&mut self, error: ReactionError) {
+        self.errors.push_back(error);
+        self.truncate();
+    }
+
+    fn truncate(&mut self) {
+        while self.errors.len() > self.capacity {
+            if let Some(error) = self.errors.pop_front() {
+                log::warn!("dropping reaction error: {error}");
+            }
+        }
+    }
+}
+
+// Reaction
+
+#[derive(Debug, Deref)]
+pub struct Reaction<E> {
+    #[deref]
+    event: E,
+    identifier: event::Identifier,
+    position: event::Position,
+    tags: Vec<event::Tag>,
+}
+
+impl<E> Reaction<E> {
+    #[must_use]
+    pub fn identifier(&self) -> &event::Identifier {
+        &self.identifier
+    }
+
+    #[must_use]
+    pub fn into_inner(self) -> E {
+        self.event
+    }
+
+    #[must_use]
+    pub fn position(&self) -> &event::Position {
+        &self.position
+    }
+
+    #[must_use]
+    pub fn tags(&self) -> &[event::Tag] {
+        &self.tags
+    }
+}
+
+// Reactor
+
+type ReactFn = Box<dyn FnMut(&CandidateEvent, event::Position) -> Result<(), Error> + Send>;
+
+struct Reactor {
+    react: ReactFn,
+}
+
+impl Reactor {
+    fn new<E, F>(mut handler: F) -> Self
+    where
+        E: Event + 'static,
+        F: FnMut(Reaction<E>) + Send + 'static,
+    {
+        Self {
+            react: Box::new(move |event, position| {
+                if !E::identifiers()?.contains(&event.identifier()) {
+                    return Ok(());
+                }
+
+                let inner_event = revision::from_slice::<E>(event.data().as_ref())
+                    .map_err(|_| Error::data("deserialization error"))?;
+
+                handler(Reaction {
+                    event: inner_event,
+                    identifier: event.identifier().clone(),
+                    position,
+                    tags: event.tags().to_vec(),
+                });
+
+                Ok(())
+            }),
+        }
+    }
+}
+
+impl Debug for Reactor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Reactor").finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,907 @@
+//! See the `eventric-surface` crate for full documentation, including
+//! module-level documentation.
+
+use std::{
+    collections::BTreeMap,
+    fmt::{
+        self,
+        Debug,
+        Formatter,
+    },
+    iter,
+    marker::PhantomData,
+    ops::{
+        Deref,
+        DerefMut,
+    },
+    sync::{
+        Arc,
+        Mutex,
+        OnceLock,
+        PoisonError,
+    },
+    time::{
+        Duration,
+        SystemTime,
+        UNIX_EPOCH,
+    },
+};
+
+use eventric_stream::{
+    event::{
+        self,
+        CandidateEvent,
+    },
+    stream::{
+        iterate::IterateSelect,
+        select::{
+            EventAndMask,
+            Selection,
+            Selections,
+            Selector,
+        },
+    },
+};
+use fancy_constructor::new;
+use revision::revisioned;
+
+use crate::{
+    action::{
+        Act,
+        Action,
+        Context,
+        Invariants,
+        Select,
+        Update,
+        composite::Composite,
+    },
+    core::Enactor,
+    error::{
+        Error,
+        ScheduleError,
+    },
+    event::{
+        Event,
+        Events,
+        Identifier,
+        Specifiers,
+        Tags,
+    },
+    projection::Selectors,
+};
+
+// =================================================================================================
+// Schedule
+// =================================================================================================
+
+// Clock
+
+pub trait Clock {
+    fn now(&self) -> SystemTime;
+}
+
+// Manual Clock
+
+#[derive(Clone, Debug)]
+pub struct ManualClock {
+    now: Arc<Mutex<SystemTime>>,
+}
+
+impl ManualClock {
+    #[must_use]
+    pub fn new(now: SystemTime) -> Self {
+        let now = Arc::new(Mutex::new(now));
+
+        Self { now }
+    }
+
+    pub fn advance(&self, duration: Duration) {
+        *self.now.lock().unwrap_or_else(PoisonError::into_inner) += duration;
+    }
+
+    pub fn set(&self, now: SystemTime) {
+        *self.now.lock().unwrap_or_else(PoisonError::into_inner) = now;
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> SystemTime {
+        *self.now.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+// System Clock
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Deadline
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Deadline {
+    Cancel {
+        name: String,
+        key: String,
+    },
+    Schedule {
+        name: String,
+        key: String,
+        after: Duration,
+    },
+}
+
+// Deadlines
+
+pub trait Deadlines {
+    fn deadlines(&self) -> Vec<Deadline>;
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Scheduler
+
+type Handler<S> = Box<dyn FnMut(&mut S, &TimerScheduled) -> Result<bool, Error> + Send>;
+
+pub struct Scheduler<S, C> {
+    clock: C,
+    handlers: BTreeMap<String, Handler<S>>,
+    pending: BTreeMap<String, TimerScheduled>,
+    position: Option<event::Position>,
+    sourced: Option<u64>,
+    sources: Vec<Source>,
+}
+
+impl<S, C> Scheduler<S, C>
+where
+    S: Enactor + IterateSelect,
+    C: Clock,
+{
+    #[must_use]
+    pub fn new(clock: C) -> Self {
+        Self {
+            clock,
+            handlers: BTreeMap::new(),
+            pending: BTreeMap::new(),
+            position: None,
+            sourced: None,
+            sources: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn deadlines<E>(mut self) -> Self
+    where
+        E: Deadlines + Event,
+    {
+        self.sources.push(Source::new::<E>());
+        self
+    }
+
+    #[must_use]
+    pub fn on<A, N>(mut self, name: N, action: fn(&str) -> A) -> Self
+    where
+        A: Action + Context<Deps = ()> + 'static,
+        N: Into<String>,
+    {
+        let handler: Handler<S> = Box::new(move |stream, timer| {
+            let fire = FireTimer::<A::Err>::new(timer.clone());
+
+            if stream
+                .try_enact_with(Composite::new(action(&timer.key), fire), ((), ()))?
+                .is_ok()
+            {
+                return Ok(true);
+            }
+
+            stream
+                .try_enact_with(FireTimer::<Error>::new(timer.clone()), ())?
+                .ok();
+
+            Ok(false)
+        });
+
+        self.handlers.insert(name.into(), handler);
+        self
+    }
+
+    pub fn run(&mut self, stream: &mut S) -> Result<usize, Error> {
+        loop {
+            let deadlines = self.read(stream)?;
+
+            if deadlines.is_empty() {
+                break;
+            }
+
+            for (source, at, deadline) in deadlines {
+                self.apply(stream, source, at, &deadline)?;
+            }
+        }
+
+        let now = millis(self.clock.now());
+        let due = self
+            .pending
+            .values()
+            .filter(|timer| timer.due <= now)
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let mut fired = 0;
+
+        for timer in due {
+            if let Some(handler) = self.handlers.get_mut(&timer.name)
+                && handler(stream, &timer)?
+            {
+                fired += 1;
+            }
+        }
+
+        Ok(fired)
+    }
+
+    fn apply(
+        &self,
+        stream: &mut S,
+        source: u64,
+        at: SystemTime,
+        deadline: &Deadline,
+    ) -> Result<(), Error> {
+        loop {
+            let enacted = match deadline {
+                Deadline::Cancel { name, key } => {
+                    let mut cancel = CancelTimer::<Error>::new(name, key);
+
+                    cancel.timer.source = Some(source);
+                    stream.enact(cancel)
+                }
+                Deadline::Schedule { name, key, .. } if !self.handlers.contains_key(name) => {
+                    log::warn!("rejecting timer {name}:{key}, as no handler is registered");
+
+                    return Ok(());
+                }
+                Deadline::Schedule { name, key, after } => {
+                    let mut timer = TimerScheduled::new(name, key, at + *after);
+
+                    timer.source = Some(source);
+                    stream.enact(ScheduleTimer::<Error>::new(timer))
+                }
+            };
+
+            match enacted {
+                Err(Error::Stream(eventric_stream::error::Error::Concurrency)) => {}
+                enacted => return enacted.map(|_| ()),
+            }
+        }
+    }
+
+    fn read(&mut self, stream: &S) -> Result<Vec<(u64, SystemTime, Deadline)>, Error> {
+        let mut specifiers = TimerScheduled::specifiers()?;
+
+        specifiers.extend(TimerFired::specifiers()?);
+        specifiers.extend(TimerCancelled::specifiers()?);
+
+        for source in &self.sources {
+            specifiers.extend((source.specifiers)()?);
+        }
+
+        let selection = Selection::new([Selector::specifiers(specifiers)?])?;
+        let selections = Selections::new(iter::once(selection))?;
+
+        let (events, _) = stream.iter_select(selections, self.position);
+
+        let mut deadlines = Vec::new();
+
+        for event in events {
+            let event = event?.event;
+            let position = *event.position();
+            let at = UNIX_EPOCH + Duration::from_nanos(**event.timestamp());
+
+            if self.position.is_some_and(|last| position <= last) {
+                continue;
+            }
+
+            self.position = Some(position);
+
+            let identifier = event.identifier();
+            let data = event.data().as_ref();
+
+            if identifier == TimerScheduled::identifier()? {
+                let timer = decode::<TimerScheduled>(data)?;
+
+                self.sourced = self.sourced.max(timer.source);
+                self.pending.insert(timer.id(), timer);
+            } else if identifier == TimerFired::identifier()? {
+                self.pending.remove(&decode::<TimerFired>(data)?.id());
+            } else if identifier == TimerCancelled::identifier()? {
+                let timer = decode::<TimerCancelled>(data)?;
+
+                self.sourced = self.sourced.max(timer.source);
+                self.pending.remove(&timer.id());
+            } else {
+                for source in &self.sources {
+                    if (source.identifiers)()?.contains(&identifier) {
+                        for deadline in (source.deadlines)(data)? {
+                            deadlines.push((*position, at, deadline));
+                        }
+                    }
+                }
+            }
+        }
+
+        deadlines.retain(|(source, ..)| Some(*source) > self.sourced);
+
+        Ok(deadlines)
+    }
+}
+
+impl<S, C> Debug for Scheduler<S, C>
+where
+    C: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Scheduler")
+            .field("clock", &self.clock)
+            .field("handlers", &self.handlers.keys())
+            .field("pending", &self.pending.keys())
+            .field("position", &self.position)
+            .field("sourced", &self.sourced)
+            .field("sources", &self.sources)
+            .finish()
+    }
+}
+
+// Source
+
+type DeadlinesFn = fn(&[u8]) -> Result<Vec<Deadline>, Error>;
+
+struct Source {
+    deadlines: DeadlinesFn,
+    identifiers: fn() -> Result<Vec<&'static event::Identifier>, eventric_stream::error::Error>,
+    specifiers: fn() -> Result<Vec<event::Specifier>, eventric_stream::error::Error>,
+}
+
+impl Source {
+    fn new<E>() -> Self
+    where
+        E: Deadlines + Event,
+    {
+        Self {
+            deadlines: |data| decode::<E>(data).map(|event| event.deadlines()),
+            identifiers: E::identifiers,
+            specifiers: E::specifiers,
+        }
+    }
+}
+
+impl Debug for Source {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Source").finish_non_exhaustive()
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Cancel Timer
+
+#[derive(Debug)]
+pub struct CancelTimer<E> {
+    error: PhantomData<fn() -> E>,
+    timer: TimerCancelled,
+}
+
+impl<E> CancelTimer<E> {
+    #[must_use]
+    pub fn new<N, K>(name: N, key: K) -> Self
+    where
+        N: Into<String>,
+        K: Into<String>,
+    {
+        let error = PhantomData;
+        let timer = TimerCancelled::new(name, key);
+
+        Self { error, timer }
+    }
+}
+
+impl<E> Action for CancelTimer<E> where E: From<Error> {}
+
+impl<E> Act for CancelTimer<E>
+where
+    E: From<Error>,
+{
+    type Err = E;
+    type Ok = bool;
+
+    fn action(&mut self, context: &mut Self::Context) -> Result<Self::Ok, Self::Err> {
+        if !context.pending {
+            return Ok(false);
+        }
+
+        context.append(&self.timer).map_err(Error::from)?;
+
+        Ok(true)
+    }
+}
+
+impl<E> Context for CancelTimer<E> {
+    type Context = TimerContext;
+
+    fn context(&self, (): Self::Deps) -> Result<Self::Context, Error> {
+        Ok(TimerContext::new(Events::new()))
+    }
+}
+
+impl<E> Invariants for CancelTimer<E> where E: From<Error> {}
+
+impl<E> Select for CancelTimer<E> {
+    fn select(&self, _: &Self::Context) -> Result<Vec<Selection>, Error> {
+        timer_select(&self.timer.id())
+    }
+
+    fn selectors(&self, _: &Self::Context) -> Result<Vec<Selectors>, Error> {
+        timer_selectors(&self.timer.id())
+    }
+}
+
+impl<E> Update for CancelTimer<E> {
+    fn update(
+        &self,
+        context: &mut Self::Context,
+        event: &EventAndMask,
+        _: &[Selectors],
+        offset: usize,
+    ) -> Result<usize, Error> {
+        if event.mask[offset] {
+            timer_update(
+                context,
+                event.event.identifier(),
+                event.event.data().as_ref(),
+            )?;
+        }
+
+        Ok(offset + 1)
+    }
+
+    fn update_candidate(
+        &self,
+        context: &mut Self::Context,
+        event: &CandidateEvent,
+        _: &[Selectors],
+        offset: usize,
+    ) -> Result<usize, Error> {
+        if event
+            .tags()
+            .contains(&event::tag!(timer, &self.timer.id())?)
+        {
+            timer_update(context, event.identifier(), event.data().as_ref())?;
+        }
+
+        Ok(offset + 1)
+    }
+}
+
+// Fire Timer
+
+#[derive(Debug)]
+pub struct FireTimer<E> {
+    error: PhantomData<fn() -> E>,
+    timer: TimerScheduled,
+}
+
+impl<E> FireTimer<E> {
+    #[must_use]
+    pub fn new(timer: TimerScheduled) -> Self {
+        let error = PhantomData;
+
+        Self { error, timer }
+    }
+}
+
+impl<E> Action for FireTimer<E> where E: From<Error> {}
+
+impl<E> Act for FireTimer<E>
+where
+    E: From<Error>,
+{
+    type Err = E;
+
+    fn action(&mut self, context: &mut Self::Context) -> Result<Self::Ok, Self::Err> {
+        let fired = TimerFired::new(&self.timer.name, &self.timer.key);
+
+        context.append(&fired).map_err(Error::from)?;
+
+        Ok(())
+    }
+}
+
+impl<E> Context for FireTimer<E> {
+    type Context = TimerContext;
+
+    fn context(&self, (): Self::Deps) -> Result<Self::Context, Error> {
+        Ok(TimerContext::new(Events::new()))
+    }
+}
+
+impl<E> Invariants for FireTimer<E>
+where
+    E: From<Error>,
+{
+    fn check(&self, context: &Self::Context) -> Result<(), Self::Err> {
+        if context.pending {
+            Ok(())
+        } else {
+            Err(Error::from(ScheduleError::NotPending(self.timer.id())).into())
+        }
+    }
+}
+
+impl<E> Select for FireTimer<E> {
+    fn select(&self, _: &Self::Context) -> Result<Vec<Selection>, Error> {
+        timer_select(&self.timer.id())
+    }
+
+    fn selectors(&self, _: &Self::Context) -> Result<Vec<Selectors>, Error> {
+        timer_selectors(&self.timer.id())
+    }
+}
+
+impl<E> Update for FireTimer<E> {
+    fn update(
+        &self,
+        context: &mut Self::Context,
+        event: &EventAndMask,
+        _: &[Selectors],
+        offset: usize,
+    ) -> Result<usize, Error> {
+        if event.mask[offset] {
+            timer_update(
+                context,
+                event.event.identifier(),
+                event.event.data().as_ref(),
+            )?;
+        }
+
+        Ok(offset + 1)
+    }
+
+    fn update_candidate(
+        &self,
+        context: &mut Self::Context,
+        event: &CandidateEvent,
+        _: &[Selectors],
+        offset: usize,
+    ) -> Result<usize, Error> {
+        if event
+            .tags()
+            .contains(&event::tag!(timer, &self.timer.id())?)
+        {
+            timer_update(context, event.identifier(), event.data().as_ref())?;
+        }
+
+        Ok(offset + 1)
+    }
+}
+
+// Schedule Timer
+
+#[derive(Debug)]
+pub struct ScheduleTimer<E> {
+    error: PhantomData<fn() -> E>,
+    timer: TimerScheduled,
+}
+
+impl<E> ScheduleTimer<E> {
+    #[must_use]
+    pub fn new(timer: TimerScheduled) -> Self {
+        let error = PhantomData;
+
+        Self { error, timer }
+    }
+}
+
+impl<E> Action for ScheduleTimer<E> where E: From<Error> {}
+
+impl<E> Act for ScheduleTimer<E>
+where
+    E: From<Error>,
+{
+    type Err = E;
+    type Ok = bool;
+
+    fn action(&mut self, context: &mut Self::Context) -> Result<Self::Ok, Self::Err> {
+        if self.timer.source.is_some() && context.source >= self.timer.source {
+            return Ok(false);
+        }
+
+        context.append(&self.timer).map_err(Error::from)?;
+
+        Ok(true)
+    }
+}
+
+impl<E> Context for ScheduleTimer<E> {
+    type Context = TimerContext;
+
+    fn context(&self, (): Self::Deps) -> Result<Self::Context, Error> {
+        Ok(TimerContext::new(Events::new()))
+    }
+}
+
+impl<E> Invariants for ScheduleTimer<E> where E: From<Error> {}
+
+impl<E> Select for ScheduleTimer<E> {
+    fn select(&self, _: &Self::Context) -> Result<Vec<Selection>, Error> {
+        timer_select(&self.timer.id())
+    }
+
+    fn selectors(&self, _: &Self::Context) -> Result<Vec<Selectors>, Error> {
+        timer_selectors(&self.timer.id())
+    }
+}
+
+impl<E> Update for ScheduleTimer<E> {
+    fn update(
+        &self,
+        context: &mut Self::Context,
+        event: &EventAndMask,
+        _: &[Selectors],
+        offset: usize,
+    ) -> Result<usize, Error> {
+        if event.mask[offset] {
+            timer_update(
+                context,
+                event.event.identifier(),
+                event.event.data().as_ref(),
+            )?;
+        }
+
+        Ok(offset + 1)
+    }
+
+    fn update_candidate(
+        &self,
+        context: &mut Self::Context,
+        event: &CandidateEvent,
+        _: &[Selectors],
+        offset: usize,
+    ) -> Result<usize, Error> {
+        if event
+            .tags()
+            .contains(&event::tag!(timer, &self.timer.id())?)
+        {
+            timer_update(context, event.identifier(), event.data().as_ref())?;
+        }
+
+        Ok(offset + 1)
+    }
+}
+
+// Timer Context
+
+#[derive(new, Debug)]
+pub struct TimerContext {
+    events: Events,
+    #[new(default)]
+    pub pending: bool,
+    #[new(default)]
+    pub source: Option<u64>,
+}
+
+impl Deref for TimerContext {
+    type Target = Events;
+
+    fn deref(&self) -> &Self::Target {
+        &self.events
+    }
+}
+
+impl DerefMut for TimerContext {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.events
+    }
+}
+
+impl From<TimerContext> for Events {
+    fn from(context: TimerContext) -> Self {
+        context.events
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Timer Cancelled
+
+#[revisioned(revision = 1)]
+#[derive(new, Clone, Debug)]
+pub struct TimerCancelled {
+    #[new(into)]
+    pub name: String,
+    #[new(into)]
+    pub key: String,
+    #[new(default)]
+    pub source: Option<u64>,
+}
+
+impl TimerCancelled {
+    #[must_use]
+    pub fn id(&self) -> String {
+        format!("{}:{}", self.name, self.key)
+    }
+}
+
+impl Event for TimerCancelled {}
+
+impl Identifier for TimerCancelled {
+    fn identifier() -> Result<&'static event::Identifier, eventric_stream::error::Error> {
+        static IDENTIFIER: OnceLock<event::Identifier> = OnceLock::new();
+
+        IDENTIFIER.get_or_try_init(|| event::Identifier::new("eventric_timer_cancelled"))
+    }
+}
+
+impl Tags for TimerCancelled {
+    fn tags(&self) -> Result<Vec<event::Tag>, eventric_stream::error::Error> {
+        Ok(vec![event::tag!(timer, &self.id())?])
+    }
+}
+
+// Timer Fired
+
+#[revisioned(revision = 1)]
+#[derive(new, Clone, Debug)]
+pub struct TimerFired {
+    #[new(into)]
+    pub name: String,
+    #[new(into)]
+    pub key: String,
+}
+
+impl TimerFired {
+    #[must_use]
+    pub fn id(&self) -> String {
+        format!("{}:{}", self.name, self.key)
+    }
+}
+
+impl Event for TimerFired {}
+
+impl Identifier for TimerFired {
+    fn identifier() -> Result<&'static event::Identifier, eventric_stream::error::Error> {
+        static IDENTIFIER: OnceLock<event::Identifier> = OnceLock::new();
+
+        IDENTIFIER.get_or_try_init(|| event::Identifier::new("eventric_timer_fired"))
+    }
+}
+
+impl Tags for TimerFired {
+    fn tags(&self) -> Result<Vec<event::Tag>, eventric_stream::error::Error> {
+        Ok(vec![event::tag!(timer, &self.id())?])
+    }
+}
+
+// Timer Scheduled
+
+#[revisioned(revision = 1)]
+#[derive(Clone, Debug)]
+pub struct TimerScheduled {
+    pub name: String,
+    pub key: String,
+    pub due: u64,
+    pub source: Option<u64>,
+}
+
+impl TimerScheduled {
+    #[must_use]
+    pub fn new<N, K>(name: N, key: K, due: SystemTime) -> Self
+    where
+        N: Into<String>,
+        K: Into<String>,
+    {
+        let name = name.into();
+        let key = key.into();
+        let due = millis(due);
+        let source = None;
+
+        Self {
+            name,
+            key,
+            due,
+            source,
+        }
+    }
+
+    #[must_use]
+    pub fn due(&self) -> SystemTime {
+        UNIX_EPOCH + Duration::from_millis(self.due)
+    }
+
+    #[must_use]
+    pub fn id(&self) -> String {
+        format!("{}:{}", self.name, self.key)
+    }
+}
+
+impl Event for TimerScheduled {}
+
+impl Identifier for TimerScheduled {
+    fn identifier() -> Result<&'static event::Identifier, eventric_stream::error::Error> {
+        static IDENTIFIER: OnceLock<event::Identifier> = OnceLock::new();
+
+        IDENTIFIER.get_or_try_init(|| event::Identifier::new("eventric_timer_scheduled"))
+    }
+}
+
+impl Tags for TimerScheduled {
+    fn tags(&self) -> Result<Vec<event::Tag>, eventric_stream::error::Error> {
+        Ok(vec![event::tag!(timer, &self.id())?])
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Functions
+
+fn timer_select(id: &str) -> Result<Vec<Selection>, Error> {
+    let mut specifiers = TimerScheduled::specifiers()?;
+
+    specifiers.extend(TimerFired::specifiers()?);
+    specifiers.extend(TimerCancelled::specifiers()?);
+
+    let tag = event::tag!(timer, id)?;
+    let selector = Selector::specifiers_and_tags(specifiers, [tag])?;
+
+    Ok(vec![Selection::new([selector])?])
+}
+
+fn timer_selectors(id: &str) -> Result<Vec<Selectors>, Error> {
+    let identifiers = [
+        TimerScheduled::identifier()?,
+        TimerFired::identifier()?,
+        TimerCancelled::identifier()?,
+    ];
+    let tag = event::tag!(timer, id)?;
+
+    Ok(vec![Selectors::new(&[None]).selector(identifiers, [tag])])
+}
+
+fn timer_update(
+    context: &mut TimerContext,
+    identifier: &event::Identifier,
+    data: &[u8],
+) -> Result<(), Error> {
+    if identifier == TimerScheduled::identifier()? {
+        context.pending = true;
+        context.source = context.source.max(decode::<TimerScheduled>(data)?.source);
+    } else if identifier == TimerFired::identifier()? {
+        context.pending = false;
+    } else if identifier == TimerCancelled::identifier()? {
+        context.pending = false;
+        context.source = context.source.max(decode::<TimerCancelled>(data)?.source);
+    }
+
+    Ok(())
+}
+
+fn decode<E>(data: &[u8]) -> Result<E, Error>
+where
+    E: Event,
+{
+    revision::from_slice(data)
+        .map_err(|_| eventric_stream::error::Error::data("deserialization error").into())
+}
+
+fn millis(time: SystemTime) -> u64 {
+    let millis = time
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis();
+
+    u64::try_from(millis).unwrap_or(u64::MAX)
+}
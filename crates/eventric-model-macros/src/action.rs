@@ -3,6 +3,7 @@
 use darling::{
     FromDeriveInput,
     FromMeta,
+    ast::NestedMeta,
 };
 use heck::AsSnakeCase;
 use proc_macro2::TokenStream;
@@ -16,9 +17,11 @@ use syn::{
     DeriveInput,
     Expr,
     ExprClosure,
+    Generics,
     Ident,
     Meta,
     Path,
+    Type,
     parse::{
         Parse,
         ParseStream,
@@ -26,170 +29,651 @@ use syn::{
     token::{
         At,
         Colon,
+        Comma,
+        Question,
     },
 };
 
+use crate::util::List;
+
 // =================================================================================================
 // Action
 // =================================================================================================
 
 #[derive(Debug, FromDeriveInput)]
-#[darling(attributes(action), supports(struct_named))]
+#[darling(
+    attributes(action),
+    supports(struct_named),
+    and_then = Self::validate
+)]
 pub struct Action {
     ident: Ident,
+    generics: Generics,
+    context: Option<Path>,
+    #[darling(default)]
+    decide: bool,
+    #[darling(multiple, rename = "forbid")]
+    forbids: Vec<Condition>,
+    #[darling(multiple, rename = "inject")]
+    injections: Vec<List<Inject>>,
+    #[darling(skip)]
+    order: Vec<bool>,
     #[darling(multiple, rename = "projection")]
     projections: Vec<Projection>,
+    #[darling(multiple, rename = "require")]
+    requires: Vec<Condition>,
 }
 
 impl Action {
     pub fn new(input: &DeriveInput) -> darling::Result<Self> {
-        Self::from_derive_input(input)
+        let mut action = Self::from_derive_input(input)?;
+
+        for attr in input
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("action"))
+        {
+            let list = attr.meta.require_list()?;
+
+            for meta in NestedMeta::parse_meta_list(list.tokens.clone())? {
+                if let NestedMeta::Meta(meta) = meta {
+                    if meta.path().is_ident("forbid") {
+                        action.order.push(true);
+                    } else if meta.path().is_ident("require") {
+                        action.order.push(false);
+                    }
+                }
+            }
+        }
+
+        Ok(action)
+    }
+}
+
+impl Action {
+    fn conditions(&self) -> impl Iterator<Item = (&Condition, bool)> {
+        let mut forbids = self.forbids.iter();
+        let mut requires = self.requires.iter();
+
+        self.order.iter().filter_map(move |&forbid| {
+            let condition = if forbid {
+                forbids.next()
+            } else {
+                requires.next()
+            };
+
+            condition.map(|condition| (condition, forbid))
+        })
+    }
+
+    fn injections(&self) -> impl Iterator<Item = &Inject> {
+        self.injections.iter().flat_map(AsRef::as_ref)
+    }
+
+    fn validate(self) -> darling::Result<Self> {
+        if let Some(context) = &self.context
+            && (!self.projections.is_empty() || self.injections().next().is_some())
+        {
+            return Err(darling::Error::custom(
+                "projections and injections must be declared on the context when `context` is \
+                 given",
+            )
+            .with_span(context));
+        }
+
+        Ok(self)
     }
 }
 
 impl Action {
     fn action(&self) -> TokenStream {
         let ident = &self.ident;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
 
         quote! {
             #[automatically_derived]
-            impl ::eventric_model::action::Action for #ident {}
+            impl #impl_generics ::eventric_model::action::Action for #ident #ty_generics #where_clause {}
         }
     }
 
     fn context(&self) -> TokenStream {
         let ident = &self.ident;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
         let projections = &self.projections;
 
+        let action_type = quote! { #ident #ty_generics };
         let context_type = format_ident!("{ident}Context");
+        let context_events = ContextEvents(&self.generics, &quote! { #context_type #ty_generics });
 
         let context_field_name = projections.iter().map(|p| &p.field_name);
         let context_field_type = projections.iter().map(|p| &p.field_type);
         let context_field_init = projections
             .iter()
-            .map(|proj| ProjectionInitializer(ident, proj));
+            .map(|proj| ProjectionInitializer(&action_type, proj));
+
+        let inject_name = self
+            .injections()
+            .map(|inject| &inject.name)
+            .collect::<Vec<_>>();
+        let inject_type = self
+            .injections()
+            .map(|inject| &inject.ty)
+            .collect::<Vec<_>>();
+
+        let (context_deps, context_deps_param, context_deps_type) = if inject_name.is_empty() {
+            (None, None, None)
+        } else {
+            let deps_type = format_ident!("{ident}Deps");
+
+            (
+                Some(quote! {
+                    #[derive(Debug)]
+                    pub struct #deps_type {
+                        #(pub #inject_name: #inject_type,)*
+                    }
+                }),
+                Some(quote! { , deps: #deps_type }),
+                Some(quote! { type Deps = #deps_type; }),
+            )
+        };
+
+        let (context_deps_name, context_deps_arg) = match context_deps_param {
+            Some(_) => (quote! { deps }, Some(quote! { , deps })),
+            None => (quote! { _ }, None),
+        };
+
+        let (context_marker, context_marker_init) = if self.generics.params.is_empty() {
+            (None, None)
+        } else {
+            (
+                Some(quote! {
+                    #[doc(hidden)]
+                    pub _action: ::std::marker::PhantomData<fn() -> #action_type>,
+                }),
+                Some(quote! {
+                    _action: ::std::marker::PhantomData,
+                }),
+            )
+        };
 
         quote! {
             #[automatically_derived]
-            impl ::eventric_model::action::Context for #ident {
-                type Context = #context_type;
+            impl #impl_generics ::eventric_model::action::Context for #ident #ty_generics #where_clause {
+                type Context = #context_type #ty_generics;
+                #context_deps_type
 
-                fn context(&self) -> Self::Context {
-                    Self::Context::new(self)
+                fn context(
+                    &self,
+                    #context_deps_name: Self::Deps
+                ) -> ::std::result::Result<Self::Context, ::eventric_model::error::Error> {
+                    Self::Context::new(self #context_deps_arg)
                 }
             }
 
+            #context_deps
+
             #[derive(Debug)]
-            pub struct #context_type {
+            pub struct #context_type #impl_generics #where_clause {
                 pub events: eventric_model::event::Events,
-                #(pub #context_field_name: #context_field_type),*
+                #(pub #context_field_name: #context_field_type,)*
+                #(pub #inject_name: #inject_type,)*
+                #context_marker
             }
 
-            #[automatically_derived]
-            impl ::std::ops::Deref for #context_type {
-                type Target = eventric_model::event::Events;
+            #context_events
 
-                fn deref(&self) -> &Self::Target {
-                    &self.events
+            impl #impl_generics #context_type #ty_generics #where_clause {
+                pub fn new(
+                    action: &#action_type #context_deps_param
+                ) -> ::std::result::Result<Self, ::eventric_model::error::Error> {
+                    Ok(Self {
+                        events: eventric_model::event::Events::new(),
+                        #(#context_field_init,)*
+                        #(#inject_name: deps.#inject_name,)*
+                        #context_marker_init
+                    })
                 }
             }
+        }
+    }
+
+    fn decide(&self) -> TokenStream {
+        let ident = &self.ident;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
 
+        if !self.decide {
+            return TokenStream::new();
+        }
+
+        quote! {
             #[automatically_derived]
-            impl ::std::ops::DerefMut for #context_type {
-                fn deref_mut(&mut self) -> &mut Self::Target {
-                    &mut self.events
+            impl #impl_generics ::eventric_model::action::Act for #ident #ty_generics #where_clause {
+                type Err = <Self as ::eventric_model::action::Decide>::Err;
+
+                fn action(
+                    &mut self,
+                    context: &mut Self::Context
+                ) -> ::std::result::Result<Self::Ok, Self::Err> {
+                    let events = ::eventric_model::action::Decide::decide(self, context)?;
+
+                    context
+                        .extend(events)
+                        .map_err(::eventric_model::error::Error::from)?;
+
+                    Ok(())
                 }
             }
+        }
+    }
 
-            #[automatically_derived]
-            impl ::core::convert::Into<::eventric_model::event::Events> for #context_type {
-                fn into(self) -> ::eventric_model::event::Events {
-                    self.events
+    fn invariants(&self) -> TokenStream {
+        let ident = &self.ident;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+
+        let condition_check = self
+            .conditions()
+            .map(|(condition, forbid)| ConditionCheck(condition, forbid))
+            .collect::<Vec<_>>();
+        let condition_invariant = self
+            .conditions()
+            .map(|(condition, forbid)| ConditionInvariant(condition, forbid));
+
+        let invariants_check = (!condition_check.is_empty()).then(|| {
+            quote! {
+                fn check(
+                    &self,
+                    context: &Self::Context
+                ) -> ::std::result::Result<(), Self::Err> {
+                  #(#condition_check)*
+
+                    Ok(())
                 }
             }
+        });
 
-            impl #context_type {
-                pub fn new(action: &#ident) -> Self {
-                    Self {
-                        events: eventric_model::event::Events::new(),
-                        #(#context_field_init),*
-                    }
-                }
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics ::eventric_model::action::Invariants for #ident #ty_generics #where_clause {
+                const INVARIANTS: &'static [::eventric_model::action::Invariant] = &[
+                    #(#condition_invariant,)*
+                ];
+
+                #invariants_check
             }
         }
     }
 
+    fn context_fields(&self) -> Vec<ContextField<'_>> {
+        self.projections
+            .iter()
+            .map(|p| ContextField::new(&p.field_name, p.multiplicity))
+            .collect()
+    }
+
     fn select(&self) -> TokenStream {
         let ident = &self.ident;
-        let projections = &self.projections;
+        let (_, ty_generics, _) = self.generics.split_for_impl();
+        let context_fields = self.context_fields();
 
-        let context_field_name = projections.iter().map(|p| &p.field_name);
+        let action_type = quote! { #ident #ty_generics };
 
-        quote! {
+        ActionSelect(&self.generics, &action_type, &context_fields).into_token_stream()
+    }
+
+    fn update(&self) -> TokenStream {
+        let ident = &self.ident;
+        let (_, ty_generics, _) = self.generics.split_for_impl();
+        let context_fields = self.context_fields();
+
+        let action_type = quote! { #ident #ty_generics };
+
+        ActionUpdate(&self.generics, &action_type, &context_fields).into_token_stream()
+    }
+}
+
+impl ToTokens for Action {
+    #[rustfmt::skip]
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.append_all(self.action());
+        tokens.append_all(self.decide());
+        tokens.append_all(self.invariants());
+
+        if self.context.is_none() {
+            tokens.append_all(self.context());
+            tokens.append_all(self.select());
+            tokens.append_all(self.update());
+        }
+    }
+}
+
+// Action Composites
+
+pub struct ActionSelect<'a>(
+    pub &'a Generics,
+    pub &'a TokenStream,
+    pub &'a [ContextField<'a>],
+);
+
+impl ToTokens for ActionSelect<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let ActionSelect(generics, action_type, context_fields) = *self;
+        let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+        let context_field_projections = context_fields
+            .iter()
+            .map(ContextField::projections)
+            .collect::<Vec<_>>();
+
+        tokens.append_all(quote! {
             #[automatically_derived]
-            impl ::eventric_model::action::Select for #ident {
+            impl #impl_generics ::eventric_model::action::Select for #action_type #where_clause {
                 fn select(
                     &self,
                     context: &Self::Context
                 ) -> ::std::result::Result<
-                    ::eventric_stream::stream::select::Selections,
-                    ::eventric_stream::error::Error
+                    ::std::vec::Vec<::eventric_stream::stream::select::Selection>,
+                    ::eventric_model::error::Error
                 > {
-                    ::eventric_stream::stream::select::Selections::new([
-                        #(::eventric_model::projection::Select::select(&context.#context_field_name)?),*
-                    ])
+                    let mut selections: ::std::vec::Vec<
+                        ::eventric_stream::stream::select::Selection
+                    > = ::std::vec::Vec::new();
+
+                  #(for projection in #context_field_projections {
+                        selections.push(::eventric_model::projection::Select::select(projection)?);
+                    })*
+
+                    Ok(selections)
+                }
+
+                fn selectors(
+                    &self,
+                    context: &Self::Context
+                ) -> ::std::result::Result<
+                    ::std::vec::Vec<::eventric_model::projection::Selectors>,
+                    ::eventric_model::error::Error
+                > {
+                    let mut selectors: ::std::vec::Vec<
+                        ::eventric_model::projection::Selectors
+                    > = ::std::vec::Vec::new();
+
+                  #(for projection in #context_field_projections {
+                        selectors.push(::eventric_model::projection::Select::selectors(projection)?);
+                    })*
+
+                    Ok(selectors)
                 }
             }
-        }
+        });
     }
+}
 
-    fn update(&self) -> TokenStream {
-        let ident = &self.ident;
-        let projections = &self.projections;
+pub struct ActionUpdate<'a>(
+    pub &'a Generics,
+    pub &'a TokenStream,
+    pub &'a [ContextField<'a>],
+);
 
-        let context_field_name = projections.iter().map(|p| &p.field_name);
-        let context_field_index = 0..projections.len();
+impl ToTokens for ActionUpdate<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let ActionUpdate(generics, action_type, context_fields) = *self;
+        let (impl_generics, _, where_clause) = generics.split_for_impl();
 
-        quote! {
+        let context_field_projections_mut = context_fields
+            .iter()
+            .map(ContextField::projections_mut)
+            .collect::<Vec<_>>();
+
+        tokens.append_all(quote! {
             #[automatically_derived]
-            impl ::eventric_model::action::Update for #ident {
+            impl #impl_generics ::eventric_model::action::Update for #action_type #where_clause {
                 fn update(
                     &self,
                     context: &mut Self::Context,
-                    event: &::eventric_stream::stream::select::EventAndMask
-                ) -> ::std::result::Result<(), ::eventric_stream::error::Error> {
-                    let mut dispatch_event = None;
-
-                    #({
-                        if event.mask[#context_field_index] && dispatch_event.is_none() {
+                    event: &::eventric_stream::stream::select::EventAndMask,
+                    selectors: &[::eventric_model::projection::Selectors],
+                    offset: usize,
+                ) -> ::std::result::Result<usize, ::eventric_model::error::Error> {
+                    let mut dispatch_event: ::std::option::Option<
+                        ::eventric_model::projection::DispatchEvent
+                    > = ::std::option::Option::None;
+                    let mut index = offset;
+
+                  #(for projection in #context_field_projections_mut {
+                        if event.mask[index] && dispatch_event.is_none() {
                             dispatch_event = ::eventric_model::projection::Recognize::recognize(
-                                &context.#context_field_name,
+                                projection,
                                 event,
                             )?;
                         }
 
-                        if event.mask[#context_field_index] && let Some(dispatch_event) = dispatch_event.as_ref() {
+                        if event.mask[index] && let Some(dispatch_event) = dispatch_event.as_ref() {
                             ::eventric_model::projection::Dispatch::dispatch(
-                                &mut context.#context_field_name,
+                                projection,
                                 dispatch_event,
-                            );
+                                &selectors[index].selected(dispatch_event),
+                            )?;
                         }
+
+                        index += 1;
                     })*
 
-                    Ok(())
+                    Ok(index)
+                }
+
+                fn update_candidate(
+                    &self,
+                    context: &mut Self::Context,
+                    event: &::eventric_stream::event::CandidateEvent,
+                    selectors: &[::eventric_model::projection::Selectors],
+                    offset: usize,
+                ) -> ::std::result::Result<usize, ::eventric_model::error::Error> {
+                    let mut dispatch_event: ::std::option::Option<
+                        ::eventric_model::projection::DispatchEvent<::eventric_model::projection::Candidate>
+                    > = ::std::option::Option::None;
+                    let mut index = offset;
+
+                  #(for projection in #context_field_projections_mut {
+                        let selected = selectors[index].matches(event.identifier(), event.tags());
+
+                        if selected && dispatch_event.is_none() {
+                            dispatch_event = ::eventric_model::projection::Recognize::recognize_candidate(
+                                projection,
+                                event,
+                            )?;
+                        }
+
+                        if selected && let Some(dispatch_event) = dispatch_event.as_ref() {
+                            ::eventric_model::projection::Dispatch::dispatch_candidate(
+                                projection,
+                                dispatch_event,
+                                &selectors[index].selected(dispatch_event),
+                            )?;
+                        }
+
+                        index += 1;
+                    })*
+
+                    Ok(index)
+                }
+            }
+        });
+    }
+}
+
+pub struct ContextField<'a> {
+    name: &'a Ident,
+    multiplicity: Multiplicity,
+}
+
+impl<'a> ContextField<'a> {
+    pub fn new(name: &'a Ident, multiplicity: Multiplicity) -> Self {
+        Self { name, multiplicity }
+    }
+
+    fn projections(&self) -> TokenStream {
+        let name = self.name;
+
+        match self.multiplicity {
+            Multiplicity::Many | Multiplicity::Optional => quote! { context.#name.iter() },
+            Multiplicity::One => quote! { ::std::iter::once(&context.#name) },
+        }
+    }
+
+    fn projections_mut(&self) -> TokenStream {
+        let name = self.name;
+
+        match self.multiplicity {
+            Multiplicity::Many | Multiplicity::Optional => quote! { context.#name.iter_mut() },
+            Multiplicity::One => quote! { ::std::iter::once(&mut context.#name) },
+        }
+    }
+}
+
+pub struct ContextEvents<'a>(pub &'a Generics, pub &'a TokenStream);
+
+impl ToTokens for ContextEvents<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let ContextEvents(generics, context_type) = *self;
+        let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+        tokens.append_all(quote! {
+            #[automatically_derived]
+            impl #impl_generics ::std::ops::Deref for #context_type #where_clause {
+                type Target = eventric_model::event::Events;
+
+                fn deref(&self) -> &Self::Target {
+                    &self.events
+                }
+            }
+
+            #[automatically_derived]
+            impl #impl_generics ::std::ops::DerefMut for #context_type #where_clause {
+                fn deref_mut(&mut self) -> &mut Self::Target {
+                    &mut self.events
                 }
             }
+
+            #[automatically_derived]
+            impl #impl_generics ::core::convert::Into<::eventric_model::event::Events> for #context_type #where_clause {
+                fn into(self) -> ::eventric_model::event::Events {
+                    self.events
+                }
+            }
+        });
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Condition
+
+#[derive(Debug)]
+pub struct Condition {
+    pub condition: Expr,
+    pub error: Expr,
+}
+
+impl FromMeta for Condition {
+    fn from_meta(meta: &Meta) -> darling::Result<Self> {
+        let list = meta.require_list()?;
+        let input = list.tokens.clone();
+
+        syn::parse2(input).map_err(darling::Error::custom)
+    }
+}
+
+impl Parse for Condition {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let condition = Expr::parse(input)?;
+
+        if !matches!(
+            condition,
+            Expr::Closure(_) | Expr::Field(_) | Expr::MethodCall(_) | Expr::Path(_)
+        ) {
+            return Err(syn::Error::new_spanned(
+                condition,
+                "expected a path into the context or a closure",
+            ));
         }
+
+        let _ = Comma::parse(input)?;
+        let error = Expr::parse(input)?;
+
+        Ok(Self { condition, error })
     }
 }
 
-impl ToTokens for Action {
-    #[rustfmt::skip]
+// Condition Composites
+
+pub struct ConditionCheck<'a>(&'a Condition, bool);
+
+impl ToTokens for ConditionCheck<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        tokens.append_all(self.action());
-        tokens.append_all(self.context());
-        tokens.append_all(self.select());
-        tokens.append_all(self.update());
+        let ConditionCheck(Condition { condition, error }, forbid) = *self;
+
+        let condition = if let Expr::Closure(closure) = condition {
+            quote! {
+                ::std::convert::identity::<fn(&Self, &Self::Context) -> bool>(#closure)(self, context)
+            }
+        } else {
+            quote! { context.#condition }
+        };
+
+        let violated = if forbid {
+            quote! { #condition }
+        } else {
+            quote! { !(#condition) }
+        };
+
+        tokens.append_all(quote! {
+            if #violated {
+                return Err(::std::convert::Into::into(#error));
+            }
+        });
+    }
+}
+
+pub struct ConditionInvariant<'a>(&'a Condition, bool);
+
+impl ToTokens for ConditionInvariant<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let ConditionInvariant(Condition { condition, error }, forbid) = *self;
+
+        let condition = condition.to_token_stream().to_string();
+        let error = error.to_token_stream().to_string();
+        let kind = if forbid {
+            quote! { ::eventric_model::action::InvariantKind::Forbid }
+        } else {
+            quote! { ::eventric_model::action::InvariantKind::Require }
+        };
+
+        tokens.append_all(quote! {
+            ::eventric_model::action::Invariant {
+                condition: #condition,
+                error: #error,
+                kind: #kind,
+            }
+        });
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Inject
+
+#[derive(Debug)]
+pub struct Inject {
+    pub name: Ident,
+    pub ty: Type,
+}
+
+impl Parse for Inject {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let name = Ident::parse(input)?;
+        let _ = Colon::parse(input)?;
+        let ty = Type::parse(input)?;
+
+        Ok(Self { name, ty })
     }
 }
 
@@ -199,9 +683,11 @@ impl ToTokens for Action {
 
 #[derive(Debug)]
 pub struct Projection {
+    pub fallible: bool,
     pub field_name: Ident,
     pub field_type: Path,
     pub initializer: ExprClosure,
+    pub multiplicity: Multiplicity,
 }
 
 impl FromMeta for Projection {
@@ -216,50 +702,179 @@ impl FromMeta for Projection {
 impl Parse for Projection {
     #[allow(clippy::match_bool, clippy::single_match_else)]
     fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let multiplicity = Multiplicity::parse(input)?;
         let field_type = Path::parse(input)?;
 
         let field_name = match input.peek(At) {
             true => At::parse(input).and_then(|_| Ident::parse(input))?,
-            _ => {
+            _ if multiplicity == Multiplicity::One => {
                 let segment = field_type.segments.last().expect("ident");
                 let ident = segment.ident.to_string();
 
                 format_ident!("{}", AsSnakeCase(ident).to_string())
             }
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &field_type,
+                    "`many` and `optional` projections must be named, such as `@ name`",
+                ));
+            }
         };
 
         let _ = Colon::parse(input)?;
 
+        let fallible = match input.peek(Question) {
+            true => Question::parse(input).map(|_| true)?,
+            _ => false,
+        };
+
         let initializer = match ExprClosure::parse(input) {
             Ok(expr) => expr,
             _ => Expr::parse(input).and_then(|expr| syn::parse2(quote! { |this| #expr }))?,
         };
 
         Ok(Self {
+            fallible,
             field_name,
             field_type,
             initializer,
+            multiplicity,
         })
     }
 }
 
 // Projection Composites
 
-pub struct ProjectionInitializer<'a>(&'a Ident, &'a Projection);
+pub struct ProjectionInitializer<'a>(&'a TokenStream, &'a Projection);
 
 impl ToTokens for ProjectionInitializer<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let ProjectionInitializer(
             action_type,
             Projection {
+                fallible,
                 field_name,
                 field_type,
                 initializer,
+                ..
             },
         ) = *self;
 
-        tokens.append_all(quote! {
-            #field_name: ::std::convert::identity::<fn(&#action_type) -> #field_type>(#initializer)(action)
-        });
+        if *fallible {
+            tokens.append_all(quote! {
+                #field_name: ::std::convert::identity::<
+                    fn(&#action_type) -> ::std::result::Result<#field_type, _>
+                >(#initializer)(action)
+                    .map_err(::eventric_model::error::InitializeError::of::<#field_type, _>)?
+            });
+        } else {
+            tokens.append_all(quote! {
+                #field_name: ::std::convert::identity::<fn(&#action_type) -> #field_type>(#initializer)(action)
+            });
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Multiplicity
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Multiplicity {
+    Many,
+    #[default]
+    One,
+    Optional,
+}
+
+impl Parse for Multiplicity {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        if !(input.peek(Ident) && input.peek2(Ident)) {
+            return Ok(Self::One);
+        }
+
+        let ident = Ident::parse(input)?;
+
+        match ident.to_string().as_str() {
+            "many" => Ok(Self::Many),
+            "optional" => Ok(Self::Optional),
+            _ => Err(syn::Error::new_spanned(
+                ident,
+                "expected `many` or `optional`",
+            )),
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use syn::parse_quote;
+
+    use super::{
+        Multiplicity,
+        Projection,
+    };
+
+    fn projection(input: proc_macro2::TokenStream) -> Result<Projection, String> {
+        syn::parse2(input).map_err(|err| err.to_string())
+    }
+
+    #[test]
+    fn multiplicity_is_declared_explicitly() {
+        let many = projection(parse_quote! {
+            many Vec<StudentAlreadySubscribed> @ students: |this| Vec::new()
+        })
+        .unwrap();
+        let optional = projection(parse_quote! {
+            optional Option<VoucherRedeemed> @ voucher: |this| None
+        })
+        .unwrap();
+
+        assert_eq!(many.multiplicity, Multiplicity::Many);
+        assert_eq!(optional.multiplicity, Multiplicity::Optional);
+    }
+
+    #[test]
+    fn wrapper_names_do_not_imply_multiplicity() {
+        let projection = projection(parse_quote! {
+            Vec<StudentAlreadySubscribed> @ students: |this| Vec::new()
+        })
+        .unwrap();
+
+        assert_eq!(projection.multiplicity, Multiplicity::One);
+    }
+
+    #[test]
+    fn single_projections_are_named_after_their_type() {
+        let projection = projection(parse_quote! {
+            StudentAlreadySubscribed: |this| StudentAlreadySubscribed::new()
+        })
+        .unwrap();
+
+        assert_eq!(projection.field_name, "student_already_subscribed");
+    }
+
+    #[test]
+    fn many_and_optional_projections_are_named_explicitly() {
+        let error = projection(parse_quote! {
+            many Vec<StudentAlreadySubscribed>: |this| Vec::new()
+        })
+        .unwrap_err();
+
+        assert!(error.contains("must be named"));
+    }
+
+    #[test]
+    fn unknown_multiplicities_are_rejected() {
+        let error = projection(parse_quote! {
+            several Vec<StudentAlreadySubscribed> @ students: |this| Vec::new()
+        })
+        .unwrap_err();
+
+        assert!(error.contains("expected `many` or `optional`"));
     }
 }
@@ -0,0 +1,424 @@
+#![allow(clippy::needless_continue)]
+
+use darling::{
+    FromDeriveInput,
+    FromField,
+    FromMeta,
+    ast::Data,
+    util::Ignored,
+};
+use proc_macro2::TokenStream;
+use quote::{
+    ToTokens,
+    TokenStreamExt as _,
+    format_ident,
+    quote,
+};
+use syn::{
+    Attribute,
+    DeriveInput,
+    Expr,
+    Generics,
+    Ident,
+    Path,
+    Type,
+};
+
+use crate::action::{
+    ActionSelect,
+    ActionUpdate,
+    ContextEvents,
+    ContextField,
+    Multiplicity,
+};
+
+// =================================================================================================
+// Context
+// =================================================================================================
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(
+    attributes(context),
+    supports(struct_named),
+    and_then = Self::validate
+)]
+pub struct Context {
+    ident: Ident,
+    generics: Generics,
+    data: Data<Ignored, Field>,
+    action: Path,
+}
+
+impl Context {
+    pub fn new(input: &DeriveInput) -> darling::Result<Self> {
+        Self::from_derive_input(input)
+    }
+}
+
+impl Context {
+    fn fields(&self) -> Vec<&Field> {
+        self.data
+            .as_ref()
+            .take_struct()
+            .map_or_else(Vec::new, |fields| fields.fields)
+    }
+
+    fn injections(&self) -> Vec<&Field> {
+        self.fields()
+            .into_iter()
+            .filter(|field| field.inject)
+            .collect()
+    }
+
+    fn projections(&self) -> Vec<&Field> {
+        self.fields()
+            .into_iter()
+            .filter(|field| field.init.is_some() || field.try_init.is_some())
+            .collect()
+    }
+}
+
+impl Context {
+    fn validate(self) -> darling::Result<Self> {
+        let mut errors = darling::Error::accumulator();
+
+        let mut events = false;
+
+        for field in self.fields() {
+            let kinds = [field.init.is_some(), field.try_init.is_some(), field.inject];
+
+            match (
+                kinds.iter().filter(|kind| **kind).count(),
+                field.ident.as_ref(),
+            ) {
+                (0, Some(ident)) if ident == "events" => events = true,
+                (1, _) => {}
+                (_, Some(ident)) => errors.push(
+                    darling::Error::custom(
+                        "expected one of `#[projection(init = ..)]`, `#[projection(try_init = \
+                         ..)]` or `#[context(inject)]`",
+                    )
+                    .with_span(ident),
+                ),
+                _ => {}
+            }
+
+            if (field.init.is_some() || field.try_init.is_some())
+                && !matches!(field.ty, Type::Path(_))
+            {
+                errors.push(
+                    darling::Error::custom("projection fields must have a named projection type")
+                        .with_span(&field.ty),
+                );
+            }
+        }
+
+        if !events {
+            errors.push(
+                darling::Error::custom("context requires an `events` field").with_span(&self.ident),
+            );
+        }
+
+        errors.finish_with(self)
+    }
+}
+
+impl Context {
+    fn context(&self) -> TokenStream {
+        let ident = &self.ident;
+        let action = &self.action;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let projections = self.projections();
+
+        let injections = self.injections();
+
+        let context_field_name = projections.iter().map(|field| &field.ident);
+        let context_field_init = projections.iter().map(|field| FieldInitializer(field));
+        let context_events = ContextEvents(&self.generics, &quote! { #ident #ty_generics });
+
+        let context_action = (!projections.is_empty()).then(|| {
+            quote! {
+                #[allow(unused_variables)]
+                let action = self;
+            }
+        });
+
+        let inject_name = injections
+            .iter()
+            .map(|field| &field.ident)
+            .collect::<Vec<_>>();
+        let inject_type = injections.iter().map(|field| &field.ty);
+
+        let (context_deps, context_deps_name, context_deps_type) = if injections.is_empty() {
+            (None, quote! { _ }, None)
+        } else {
+            let action_ident = &self.action.segments.last().expect("action").ident;
+            let deps_type = format_ident!("{action_ident}Deps");
+
+            (
+                Some(quote! {
+                    #[derive(Debug)]
+                    pub struct #deps_type {
+                        #(pub #inject_name: #inject_type,)*
+                    }
+                }),
+                quote! { deps },
+                Some(quote! { type Deps = #deps_type; }),
+            )
+        };
+
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics ::eventric_model::action::Context for #action #where_clause {
+                type Context = #ident #ty_generics;
+                #context_deps_type
+
+                fn context(
+                    &self,
+                    #context_deps_name: Self::Deps
+                ) -> ::std::result::Result<Self::Context, ::eventric_model::error::Error> {
+                    #context_action
+
+                    Ok(#ident {
+                        events: ::eventric_model::event::Events::new(),
+                        #(#context_field_name: #context_field_init,)*
+                        #(#inject_name: deps.#inject_name,)*
+                    })
+                }
+            }
+
+            #context_deps
+
+            #context_events
+        }
+    }
+
+    fn context_fields(&self) -> Vec<ContextField<'_>> {
+        self.projections()
+            .into_iter()
+            .filter_map(|field| {
+                let ident = field.ident.as_ref()?;
+
+                Some(ContextField::new(ident, field.multiplicity))
+            })
+            .collect()
+    }
+
+    fn select(&self) -> TokenStream {
+        let action = self.action.to_token_stream();
+        let context_fields = self.context_fields();
+
+        ActionSelect(&self.generics, &action, &context_fields).into_token_stream()
+    }
+
+    fn update(&self) -> TokenStream {
+        let action = self.action.to_token_stream();
+        let context_fields = self.context_fields();
+
+        ActionUpdate(&self.generics, &action, &context_fields).into_token_stream()
+    }
+}
+
+impl ToTokens for Context {
+    #[rustfmt::skip]
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.append_all(self.context());
+        tokens.append_all(self.select());
+        tokens.append_all(self.update());
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Field
+
+#[derive(Debug, FromField)]
+#[darling(forward_attrs(context, projection), and_then = Self::parse_attrs)]
+pub struct Field {
+    ident: Option<Ident>,
+    ty: Type,
+    attrs: Vec<Attribute>,
+    #[darling(skip)]
+    init: Option<Expr>,
+    #[darling(skip)]
+    inject: bool,
+    #[darling(skip)]
+    multiplicity: Multiplicity,
+    #[darling(skip)]
+    try_init: Option<Expr>,
+}
+
+impl Field {
+    fn parse_attrs(mut self) -> darling::Result<Self> {
+        let mut errors = darling::Error::accumulator();
+
+        for attr in &self.attrs {
+            if attr.path().is_ident("context") {
+                if let Some(context) = errors.handle(FieldContext::from_meta(&attr.meta)) {
+                    self.inject |= context.inject;
+                }
+            } else if let Some(projection) = errors.handle(FieldProjection::from_meta(&attr.meta)) {
+                let multiplicity = match (projection.many, projection.optional) {
+                    (true, true) => {
+                        errors.push(
+                            darling::Error::custom("expected one of `many` or `optional`")
+                                .with_span(attr),
+                        );
+
+                        self.multiplicity
+                    }
+                    (true, _) => Multiplicity::Many,
+                    (_, true) => Multiplicity::Optional,
+                    _ => self.multiplicity,
+                };
+
+                self.init = projection.init.or(self.init);
+                self.multiplicity = multiplicity;
+                self.try_init = projection.try_init.or(self.try_init);
+            }
+        }
+
+        errors.finish_with(self)
+    }
+}
+
+#[derive(Debug, FromMeta)]
+struct FieldContext {
+    #[darling(default)]
+    inject: bool,
+}
+
+#[derive(Debug, FromMeta)]
+struct FieldProjection {
+    init: Option<Expr>,
+    #[darling(default)]
+    many: bool,
+    #[darling(default)]
+    optional: bool,
+    try_init: Option<Expr>,
+}
+
+// Field Composites
+
+pub struct FieldInitializer<'a>(&'a Field);
+
+impl ToTokens for FieldInitializer<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let FieldInitializer(Field {
+            ty, init, try_init, ..
+        }) = *self;
+
+        if let Some(try_init) = try_init {
+            tokens.append_all(quote! {
+                ::std::convert::identity::<::std::result::Result<#ty, _>>(#try_init)
+                    .map_err(::eventric_model::error::InitializeError::of::<#ty, _>)?
+            });
+        } else {
+            tokens.append_all(quote! {
+                ::std::convert::identity::<#ty>(#init)
+            });
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use syn::parse_quote;
+
+    use super::Context;
+    use crate::action::Multiplicity;
+
+    fn error(input: &syn::DeriveInput) -> String {
+        Context::new(input).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn initializers_belong_to_the_projection_attribute() {
+        let error = error(&parse_quote! {
+            #[context(action = Book)]
+            struct BookContext {
+                events: Events,
+                #[context(init = RoomBookings::new(&action.room))]
+                room_bookings: RoomBookings,
+            }
+        });
+
+        assert!(error.contains("init"));
+    }
+
+    #[test]
+    fn injections_belong_to_the_context_attribute() {
+        let error = error(&parse_quote! {
+            #[context(action = Book)]
+            struct BookContext {
+                events: Events,
+                #[projection(inject)]
+                clock: Clock,
+            }
+        });
+
+        assert!(error.contains("inject"));
+    }
+
+    #[test]
+    fn projection_fields_require_a_type_path() {
+        let error = error(&parse_quote! {
+            #[context(action = Book)]
+            struct BookContext {
+                events: Events,
+                #[projection(init = (RoomBookings::new(&action.room),))]
+                room_bookings: (RoomBookings,),
+            }
+        });
+
+        assert!(error.contains("projection fields must have a named projection type"));
+    }
+
+    #[test]
+    fn multiplicity_comes_from_the_projection_attribute() {
+        let context = Context::new(&parse_quote! {
+            #[context(action = Enrol)]
+            struct EnrolContext {
+                events: Events,
+                #[projection(init = Vec::new(), many)]
+                students: Vec<Subscribed>,
+                #[projection(init = None, optional)]
+                voucher: Option<Redeemed>,
+                #[projection(init = Vec::new())]
+                single: Vec<Subscribed>,
+            }
+        })
+        .unwrap();
+
+        let multiplicities = context
+            .fields()
+            .iter()
+            .map(|field| field.multiplicity)
+            .collect::<Vec<_>>();
+
+        assert_eq!(multiplicities, [
+            Multiplicity::One,
+            Multiplicity::Many,
+            Multiplicity::Optional,
+            Multiplicity::One
+        ]);
+    }
+
+    #[test]
+    fn multiplicities_are_exclusive() {
+        let error = error(&parse_quote! {
+            #[context(action = Enrol)]
+            struct EnrolContext {
+                events: Events,
+                #[projection(init = Vec::new(), many, optional)]
+                students: Vec<Subscribed>,
+            }
+        });
+
+        assert!(error.contains("expected one of `many` or `optional`"));
+    }
+}
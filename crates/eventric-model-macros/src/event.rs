@@ -2,7 +2,16 @@
 
 use std::collections::HashMap;
 
-use darling::FromDeriveInput;
+use darling::{
+    FromDeriveInput,
+    FromMeta,
+    FromVariant,
+    ast::{
+        Data,
+        Fields,
+    },
+    util::Ignored,
+};
 use eventric_stream::event::Identifier;
 use proc_macro2::{
     TokenStream,
@@ -18,12 +27,19 @@ use syn::{
     DeriveInput,
     Expr,
     ExprClosure,
+    Field,
+    Generics,
     Ident,
     Meta,
+    Pat,
+    WherePredicate,
     parse::{
         Parse,
         ParseStream,
+        discouraged::Speculative as _,
     },
+    parse_quote,
+    token::Comma,
 };
 
 use crate::util::List;
@@ -33,11 +49,17 @@ use crate::util::List;
 // =================================================================================================
 
 #[derive(Debug, FromDeriveInput)]
-#[darling(attributes(event), supports(struct_named))]
+#[darling(attributes(event), supports(struct_named, enum_named, enum_unit))]
 pub struct Event {
     ident: Ident,
-    #[darling(with = "parse_identifier")]
-    identifier: String,
+    generics: Generics,
+    data: Data<Variant, Ignored>,
+    #[darling(multiple, rename = "cancel")]
+    cancels: Vec<Cancel>,
+    #[darling(default, with = "parse_event_identifier")]
+    identifier: Option<EventIdentifier>,
+    #[darling(multiple, rename = "schedule")]
+    schedules: Vec<Schedule>,
     #[darling(map = "tags_map")]
     tags: Option<HashMap<Ident, List<Tag>>>,
 }
@@ -49,29 +71,80 @@ impl Event {
 }
 
 impl Event {
+    fn deadlines(&self) -> Option<TokenStream> {
+        if self.cancels.is_empty() && self.schedules.is_empty() {
+            return None;
+        }
+
+        let ident = &self.ident;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+
+        let cancel = self.cancels.iter().map(CancelDeadline);
+        let schedule = self.schedules.iter().map(ScheduleDeadline);
+
+        Some(quote! {
+            #[automatically_derived]
+            impl #impl_generics ::eventric_model::schedule::Deadlines for #ident #ty_generics #where_clause {
+                fn deadlines(&self) -> ::std::vec::Vec<::eventric_model::schedule::Deadline> {
+                    #[allow(unused_variables)]
+                    let this = self;
+
+                    ::std::vec![#(#schedule,)* #(#cancel,)*]
+                }
+            }
+        })
+    }
+
     fn event(&self) -> TokenStream {
         let ident = &self.ident;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
 
         quote! {
             #[automatically_derived]
-            impl ::eventric_model::event::Event for #ident {}
+            impl #impl_generics ::eventric_model::event::Event for #ident #ty_generics #where_clause {}
         }
     }
 
     fn identifier(&self) -> TokenStream {
         let ident = &self.ident;
-        let identifier = &self.identifier;
+        let mut generics = self.generics.clone();
+        let type_param = self.generics.type_params().map(|param| &param.ident);
+
+        generics
+            .make_where_clause()
+            .predicates
+            .extend(type_param.map(|param| -> WherePredicate {
+                parse_quote! { #param: 'static }
+            }));
+
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+        let identifier = match &self.identifier {
+            Some(EventIdentifier::Expr(expr)) if self.is_generic() => quote! {
+                ::eventric_model::event::identifier::<Self, _, _>(|| #expr)
+            },
+            Some(EventIdentifier::Expr(expr)) => quote! {
+                static IDENTIFIER: ::std::sync::OnceLock<::eventric_stream::event::Identifier> = ::std::sync::OnceLock::new();
+
+                IDENTIFIER.get_or_try_init(|| {
+                    ::eventric_stream::event::Identifier::new(::std::convert::AsRef::<str>::as_ref(&(#expr)))
+                })
+            },
+            Some(EventIdentifier::Name(name)) => quote! {
+                static IDENTIFIER: ::std::sync::OnceLock<::eventric_stream::event::Identifier> = ::std::sync::OnceLock::new();
+
+                IDENTIFIER.get_or_try_init(|| ::eventric_stream::event::Identifier::new(#name))
+            },
+            None => TokenStream::new(),
+        };
 
         quote! {
             #[automatically_derived]
-            impl ::eventric_model::event::Identifier for #ident {
+            impl #impl_generics ::eventric_model::event::Identifier for #ident #ty_generics #where_clause {
                 fn identifier() -> ::std::result::Result<
                     &'static ::eventric_stream::event::Identifier,
                     ::eventric_stream::error::Error
                 > {
-                    static IDENTIFIER: ::std::sync::OnceLock<eventric_stream::event::Identifier> = ::std::sync::OnceLock::new();
-
-                    IDENTIFIER.get_or_try_init(|| ::eventric_stream::event::Identifier::new(#identifier))
+                    #identifier
                 }
             }
         }
@@ -79,14 +152,15 @@ impl Event {
 
     fn tags(&self) -> TokenStream {
         let ident = &self.ident;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
         let tags = self.tags.as_ref();
 
-        let tag = tags_fold(ident, tags);
+        let tag = tags_fold(tags);
         let tag_count = tag.len();
 
         quote! {
             #[automatically_derived]
-            impl ::eventric_model::event::Tags for #ident {
+            impl #impl_generics ::eventric_model::event::Tags for #ident #ty_generics #where_clause {
                 fn tags(&self) -> ::std::result::Result<
                     ::std::vec::Vec<::eventric_stream::event::Tag>,
                     ::eventric_stream::error::Error
@@ -103,22 +177,269 @@ impl Event {
 }
 
 impl Event {
+    fn variants_identify(&self, variants: &[Variant]) -> TokenStream {
+        let ident = &self.ident;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+
+        let variant_ident = variants.iter().map(|variant| &variant.ident);
+        let variant_name = variants.iter().map(|variant| variant.ident.to_string());
+
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics ::eventric_model::event::Identify for #ident #ty_generics #where_clause {
+                fn identify(&self) -> ::std::result::Result<
+                    &'static ::eventric_stream::event::Identifier,
+                    ::eventric_stream::error::Error
+                > {
+                    match self {
+                      #(Self::#variant_ident { .. } => <Self as ::eventric_model::event::Variants>::variant(#variant_name),)*
+                    }
+                }
+            }
+        }
+    }
+
+    fn variants_identifiers(&self, variants: &[Variant]) -> TokenStream {
+        let ident = &self.ident;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+
+        let variant_name = variants.iter().map(|variant| variant.ident.to_string());
+
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics ::eventric_model::event::Identifiers for #ident #ty_generics #where_clause {
+                fn identifiers() -> ::std::result::Result<
+                    ::std::vec::Vec<&'static ::eventric_stream::event::Identifier>,
+                    ::eventric_stream::error::Error
+                > {
+                    Ok(::std::vec![#(<Self as ::eventric_model::event::Variants>::variant(#variant_name)?),*])
+                }
+            }
+        }
+    }
+
+    fn variants_tags(&self, variants: &[Variant]) -> TokenStream {
+        let ident = &self.ident;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+
+        let variant_tags = variants.iter().map(VariantTags);
+
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics ::eventric_model::event::Tags for #ident #ty_generics #where_clause {
+                #[allow(unused_variables)]
+                fn tags(&self) -> ::std::result::Result<
+                    ::std::vec::Vec<::eventric_stream::event::Tag>,
+                    ::eventric_stream::error::Error
+                > {
+                    match self {
+                      #(#variant_tags)*
+                    }
+                }
+            }
+        }
+    }
+
+    fn variants_variants(&self, variants: &[Variant]) -> TokenStream {
+        let ident = &self.ident;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+
+        let variant_name = variants.iter().map(|variant| variant.ident.to_string());
+        let variant_identifier = variants.iter().map(|variant| &variant.identifier);
+
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics ::eventric_model::event::Variants for #ident #ty_generics #where_clause {
+                fn variant(name: &str) -> ::std::result::Result<
+                    &'static ::eventric_stream::event::Identifier,
+                    ::eventric_stream::error::Error
+                > {
+                    match name {
+                      #(#variant_name => {
+                            static IDENTIFIER: ::std::sync::OnceLock<::eventric_stream::event::Identifier> = ::std::sync::OnceLock::new();
+
+                            IDENTIFIER.get_or_try_init(|| ::eventric_stream::event::Identifier::new(#variant_identifier))
+                        })*
+                        _ => Err(::eventric_stream::error::Error::data("unknown event variant")),
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Event {
+    fn is_generic(&self) -> bool {
+        self.generics.type_params().next().is_some()
+            || self.generics.const_params().next().is_some()
+    }
+
     fn validate(self) -> darling::Result<Self> {
-        self.validate_identifier().map(|()| self)
+        match &self.data {
+            Data::Enum(variants) => self.validate_enum(variants),
+            Data::Struct(_) => self.validate_struct(),
+        }
+        .map(|()| self)
+    }
+
+    fn validate_enum(&self, variants: &[Variant]) -> darling::Result<()> {
+        let mut errors = darling::Error::accumulator();
+
+        if self.is_generic() {
+            errors.push(
+                darling::Error::custom(
+                    "generic enum events are not supported, as each variant identifier would be \
+                     shared by every instantiation",
+                )
+                .with_span(&self.ident),
+            );
+        }
+
+        if self.identifier.is_some() {
+            errors.push(darling::Error::custom(
+                "enum events declare an identifier on each variant",
+            ));
+        }
+
+        if self.tags.is_some() {
+            errors.push(darling::Error::custom(
+                "enum events declare tags on each variant",
+            ));
+        }
+
+        for variant in variants {
+            errors.handle(validate_identifier(&variant.identifier));
+        }
+
+        errors.finish()
     }
 
-    fn validate_identifier(&self) -> darling::Result<()> {
-        Identifier::new(&self.identifier)
-            .map(|_| ())
-            .map_err(darling::Error::custom)
+    fn validate_struct(&self) -> darling::Result<()> {
+        match &self.identifier {
+            Some(EventIdentifier::Expr(_)) => Ok(()),
+            Some(EventIdentifier::Name(_)) if self.is_generic() => Err(darling::Error::custom(
+                "generic events require a computed identifier, such as `identifier = ..`, so that \
+                 each instantiation is distinct",
+            )
+            .with_span(&self.ident)),
+            Some(EventIdentifier::Name(name)) => validate_identifier(name),
+            None => Err(darling::Error::missing_field("identifier")),
+        }
     }
 }
 
 impl ToTokens for Event {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         tokens.append_all(self.event());
-        tokens.append_all(self.identifier());
-        tokens.append_all(self.tags());
+
+        match &self.data {
+            Data::Enum(variants) => {
+                tokens.append_all(self.variants_identify(variants));
+                tokens.append_all(self.variants_identifiers(variants));
+                tokens.append_all(self.variants_tags(variants));
+                tokens.append_all(self.variants_variants(variants));
+            }
+            Data::Struct(_) => {
+                tokens.append_all(self.identifier());
+                tokens.append_all(self.tags());
+            }
+        }
+
+        tokens.append_all(self.deadlines());
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Deadline
+
+#[derive(Debug, FromMeta)]
+pub struct Cancel {
+    name: String,
+    key: Expr,
+}
+
+#[derive(Debug, FromMeta)]
+pub struct Schedule {
+    name: String,
+    key: Expr,
+    after: Expr,
+}
+
+// Deadline Composites
+
+pub struct CancelDeadline<'a>(&'a Cancel);
+
+impl ToTokens for CancelDeadline<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let CancelDeadline(Cancel { name, key }) = *self;
+
+        tokens.append_all(quote! {
+            ::eventric_model::schedule::Deadline::Cancel {
+                name: ::std::string::String::from(#name),
+                key: ::std::string::ToString::to_string(&(#key)),
+            }
+        });
+    }
+}
+
+pub struct ScheduleDeadline<'a>(&'a Schedule);
+
+impl ToTokens for ScheduleDeadline<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let ScheduleDeadline(Schedule { name, key, after }) = *self;
+
+        tokens.append_all(quote! {
+            ::eventric_model::schedule::Deadline::Schedule {
+                name: ::std::string::String::from(#name),
+                key: ::std::string::ToString::to_string(&(#key)),
+                after: #after,
+            }
+        });
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Variant
+
+#[derive(Debug, FromVariant)]
+#[darling(attributes(event))]
+pub struct Variant {
+    ident: Ident,
+    fields: Fields<Field>,
+    #[darling(with = "parse_identifier")]
+    identifier: String,
+    #[darling(map = "tags_map")]
+    tags: Option<HashMap<Ident, List<Tag>>>,
+}
+
+// Variant Composites
+
+pub struct VariantTags<'a>(&'a Variant);
+
+impl ToTokens for VariantTags<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let VariantTags(Variant {
+            ident,
+            fields,
+            tags,
+            ..
+        }) = *self;
+
+        let field = fields.iter().filter_map(|field| field.ident.as_ref());
+        let tag = variant_tags_fold(tags.as_ref());
+        let tag_count = tag.len();
+
+        tokens.append_all(quote! {
+            Self::#ident { #(#field),* } => {
+                let mut tags = ::std::vec::Vec::with_capacity(#tag_count);
+
+              #(tags.push(#tag?);)*
+
+                Ok(tags)
+            }
+        });
     }
 }
 
@@ -126,6 +447,14 @@ impl ToTokens for Event {
 
 // Identifier
 
+const RESERVED: &str = "eventric_";
+
+#[derive(Debug)]
+pub enum EventIdentifier {
+    Expr(Expr),
+    Name(String),
+}
+
 pub fn parse_identifier(meta: &Meta) -> darling::Result<String> {
     let identifier = meta.require_list()?;
     let identifier = identifier.tokens.clone().into_iter().collect::<Vec<_>>();
@@ -136,6 +465,25 @@ pub fn parse_identifier(meta: &Meta) -> darling::Result<String> {
     }
 }
 
+pub fn parse_event_identifier(meta: &Meta) -> darling::Result<Option<EventIdentifier>> {
+    match meta {
+        Meta::NameValue(name_value) => Ok(Some(EventIdentifier::Expr(name_value.value.clone()))),
+        _ => parse_identifier(meta).map(|name| Some(EventIdentifier::Name(name))),
+    }
+}
+
+pub fn validate_identifier(identifier: &str) -> darling::Result<()> {
+    if identifier.starts_with(RESERVED) {
+        return Err(darling::Error::custom(format!(
+            "identifiers starting with `{RESERVED}` are reserved for events written by eventric"
+        )));
+    }
+
+    Identifier::new(identifier)
+        .map(|_| ())
+        .map_err(darling::Error::custom)
+}
+
 // -------------------------------------------------------------------------------------------------
 
 // Tag
@@ -149,6 +497,13 @@ pub enum Tag {
 impl Parse for Tag {
     fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
         if let Ok(mut expr) = ExprClosure::parse(input) {
+            if expr.inputs.len() != 1 {
+                return Err(syn::Error::new_spanned(
+                    &expr.inputs,
+                    "tag closures take exactly one parameter, such as `|this| ..`",
+                ));
+            }
+
             let body = &expr.body;
             let body = syn::parse2(quote! { { #body }.into() })?;
 
@@ -157,7 +512,13 @@ impl Parse for Tag {
             return Ok(Self::ExprClosure(expr));
         }
 
-        if let Ok(ident) = Ident::parse(input) {
+        let fork = input.fork();
+
+        if let Ok(ident) = Ident::parse(&fork)
+            && (fork.is_empty() || fork.peek(Comma))
+        {
+            input.advance_to(&fork);
+
             return Ok(Self::Ident(ident));
         }
 
@@ -171,17 +532,17 @@ impl Parse for Tag {
 
 // Composites
 
-pub struct TagInitialize<'a>(pub &'a Ident, pub &'a Ident, pub &'a Tag);
+pub struct TagInitialize<'a>(pub &'a Ident, pub &'a Tag);
 
 impl ToTokens for TagInitialize<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        let TagInitialize(ident, prefix, tag) = *self;
+        let TagInitialize(prefix, tag) = *self;
 
         match tag {
             Tag::ExprClosure(expr) => tokens.append_all(quote! {
                 ::eventric_stream::event::tag!(
                     #prefix,
-                    ::std::convert::identity::<for<'a> fn(&'a #ident) -> ::std::borrow::Cow<'a, _>>(#expr)(&self)
+                    ::std::convert::identity::<for<'a> fn(&'a Self) -> ::std::borrow::Cow<'a, _>>(#expr)(&self)
                 )
             }),
             Tag::Ident(ident) => tokens.append_all(quote! {
@@ -194,6 +555,40 @@ impl ToTokens for TagInitialize<'_> {
     }
 }
 
+pub struct VariantTagInitialize<'a>(pub &'a Ident, pub &'a Tag);
+
+impl ToTokens for VariantTagInitialize<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let VariantTagInitialize(prefix, tag) = *self;
+
+        match tag {
+            Tag::ExprClosure(expr) => {
+                let body = &expr.body;
+                let param = expr.inputs.iter().map(|input| match input {
+                    Pat::Type(input) => quote! { let #input = self; },
+                    input => quote! { let #input: &Self = self; },
+                });
+
+                tokens.append_all(quote! {
+                    ::eventric_stream::event::tag!(#prefix, {
+                      #(#param)*
+
+                        let tag: ::std::borrow::Cow<'_, _> = #body;
+
+                        tag
+                    })
+                });
+            }
+            Tag::Ident(ident) => tokens.append_all(quote! {
+                ::eventric_stream::event::tag!(
+                    #prefix,
+                    #ident
+                )
+            }),
+        }
+    }
+}
+
 // Functions
 
 pub fn tags_map(tags: Option<HashMap<String, List<Tag>>>) -> Option<HashMap<Ident, List<Tag>>> {
@@ -204,15 +599,12 @@ pub fn tags_map(tags: Option<HashMap<String, List<Tag>>>) -> Option<HashMap<Iden
     })
 }
 
-pub fn tags_fold<'a>(
-    ident: &'a Ident,
-    tags: Option<&'a HashMap<Ident, List<Tag>>>,
-) -> Vec<TagInitialize<'a>> {
+pub fn tags_fold(tags: Option<&HashMap<Ident, List<Tag>>>) -> Vec<TagInitialize<'_>> {
     tags.as_ref()
         .map(|tags| {
             tags.iter().fold(Vec::new(), |mut acc, (prefix, tags)| {
                 for tag in tags.as_ref() {
-                    acc.push(TagInitialize(ident, prefix, tag));
+                    acc.push(TagInitialize(prefix, tag));
                 }
 
                 acc
@@ -220,3 +612,102 @@ pub fn tags_fold<'a>(
         })
         .unwrap_or_default()
 }
+
+pub fn variant_tags_fold(
+    tags: Option<&HashMap<Ident, List<Tag>>>,
+) -> Vec<VariantTagInitialize<'_>> {
+    tags.into_iter()
+        .flatten()
+        .flat_map(|(prefix, tags)| {
+            tags.as_ref()
+                .iter()
+                .map(move |tag| VariantTagInitialize(prefix, tag))
+        })
+        .collect()
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use quote::ToTokens as _;
+    use syn::parse_quote;
+
+    use super::Event;
+
+    fn expand(input: &syn::DeriveInput) -> Result<String, String> {
+        Event::new(input)
+            .map(|event| event.into_token_stream().to_string())
+            .map_err(|err| err.to_string())
+    }
+
+    #[test]
+    fn variant_tag_closures_bind_their_parameter() {
+        let expanded = expand(&parse_quote! {
+            enum Loan {
+                #[event(identifier(book_borrowed), tags(member(|this| this.member())))]
+                Borrowed { member: String },
+            }
+        })
+        .unwrap();
+
+        assert!(expanded.contains("let this : & Self = self ;"));
+    }
+
+    #[test]
+    fn tag_closures_take_one_parameter() {
+        let error = expand(&parse_quote! {
+            #[event(identifier(book_catalogued), tags(book(|| "dune")))]
+            struct BookCatalogued;
+        })
+        .unwrap_err();
+
+        assert!(error.contains("exactly one parameter"));
+    }
+
+    #[test]
+    fn generic_enums_are_rejected() {
+        let error = expand(&parse_quote! {
+            enum Loan<T> {
+                #[event(identifier(book_borrowed))]
+                Borrowed { member: T },
+            }
+        })
+        .unwrap_err();
+
+        assert!(error.contains("generic enum events are not supported"));
+    }
+
+    #[test]
+    fn generic_structs_require_a_computed_identifier() {
+        let error = expand(&parse_quote! {
+            #[event(identifier(distance_walked))]
+            struct DistanceWalked<U> { unit: U }
+        })
+        .unwrap_err();
+
+        assert!(error.contains("generic events require a computed identifier"));
+
+        let expanded = expand(&parse_quote! {
+            #[event(identifier = format!("distance_walked_in_{}", U::NAME))]
+            struct DistanceWalked<U> { unit: U }
+        })
+        .unwrap();
+
+        assert!(expanded.contains("identifier :: < Self , _ , _ >"));
+        assert!(expanded.contains("U : 'static"));
+    }
+
+    #[test]
+    fn reserved_identifiers_are_rejected() {
+        let error = expand(&parse_quote! {
+            #[event(identifier(eventric_timer_fired))]
+            struct TimerFired { name: String }
+        })
+        .unwrap_err();
+
+        assert!(error.contains("reserved"));
+    }
+}
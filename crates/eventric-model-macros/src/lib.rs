@@ -8,6 +8,7 @@
 #![allow(missing_docs)]
 
 pub(crate) mod action;
+pub(crate) mod context;
 pub(crate) mod event;
 pub(crate) mod projection;
 pub(crate) mod util;
@@ -18,6 +19,7 @@ use syn::parse_macro_input;
 
 use crate::{
     action::Action,
+    context::Context,
     event::Event,
     projection::Projection,
 };
@@ -48,6 +50,13 @@ pub fn action(input: TokenStream) -> TokenStream {
     emit_impl_or_error!(Action::new(&parse_macro_input!(input))).into()
 }
 
+// Context
+
+#[proc_macro_derive(Context, attributes(context, projection))]
+pub fn context(input: TokenStream) -> TokenStream {
+    emit_impl_or_error!(Context::new(&parse_macro_input!(input))).into()
+}
+
 // Event
 
 #[proc_macro_derive(Event, attributes(event))]
@@ -7,7 +7,13 @@ use std::collections::{
 
 use darling::{
     FromDeriveInput,
+    FromField,
     FromMeta,
+    FromVariant,
+    ast::{
+        Data,
+        Fields,
+    },
 };
 use proc_macro2::TokenStream;
 use quote::{
@@ -18,8 +24,18 @@ use quote::{
 };
 use syn::{
     DeriveInput,
+    Expr,
+    ExprClosure,
+    Generics,
     Ident,
+    Meta,
     Path,
+    parenthesized,
+    parse::{
+        Parse,
+        ParseStream,
+    },
+    token::FatArrow,
 };
 
 use crate::{
@@ -32,11 +48,24 @@ use crate::{
 // =================================================================================================
 
 #[derive(Debug, FromDeriveInput)]
-#[darling(attributes(projection), supports(struct_named))]
+#[darling(
+    attributes(projection),
+    supports(struct_named, enum_named, enum_unit),
+    and_then = Self::validate
+)]
 pub struct Projection {
     ident: Ident,
+    generics: Generics,
+    data: Data<Variant, Field>,
+    #[darling(default)]
+    candidate: bool,
+    decode: Option<Decode>,
+    #[darling(multiple, rename = "on")]
+    handlers: Vec<On>,
     #[darling(multiple, rename = "select")]
     selectors: Vec<Selector>,
+    #[darling(multiple, rename = "transition")]
+    transitions: Vec<Transition>,
 }
 
 impl Projection {
@@ -46,63 +75,377 @@ impl Projection {
 }
 
 impl Projection {
+    fn candidate(&self) -> bool {
+        let projected = self.projected();
+
+        self.candidate || self.events().iter().all(|event| projected.contains(event))
+    }
+
     fn events(&self) -> Vec<Path> {
         self.selectors
             .iter()
-            .flat_map(|selector| selector.events.as_ref())
-            .cloned()
+            .flat_map(|selector| {
+                selector.events.as_ref().iter().cloned().chain(
+                    selector
+                        .variants
+                        .as_ref()
+                        .iter()
+                        .map(|variant| variant_split(variant).0),
+                )
+            })
             .collect::<HashSet<_>>()
             .into_iter()
             .collect()
     }
 
+    fn fields(&self) -> Vec<&Field> {
+        self.data
+            .as_ref()
+            .take_struct()
+            .map_or_else(Vec::new, |fields| fields.fields)
+    }
+
+    fn projected(&self) -> Vec<Path> {
+        let mut projected = Vec::new();
+
+        let handled = self.handlers.iter().map(|handler| &handler.event);
+        let fields = self.fields();
+        let counted = fields.iter().filter_map(|field| field.count.as_ref());
+        let existing = fields.iter().filter_map(|field| field.exists.as_ref());
+        let transitioned = self.transitions.iter().map(|transition| &transition.on);
+
+        for event in handled
+            .chain(counted.flat_map(AsRef::as_ref))
+            .chain(existing.flat_map(AsRef::as_ref))
+            .chain(transitioned.flat_map(AsRef::as_ref))
+        {
+            if !projected.contains(event) {
+                projected.push(event.clone());
+            }
+        }
+
+        projected
+    }
+
     pub fn selectors(&self) -> &Vec<Selector> {
         &self.selectors
     }
+
+    fn variants(&self) -> Vec<&Variant> {
+        self.data.as_ref().take_enum().unwrap_or_default()
+    }
+}
+
+impl Projection {
+    fn validate(self) -> darling::Result<Self> {
+        let mut errors = darling::Error::accumulator();
+
+        let events = self.events();
+
+        for event in self.projected() {
+            if !events.contains(&event) {
+                errors.push(
+                    darling::Error::custom("event is not selected by any `select`")
+                        .with_span(&event),
+                );
+            }
+        }
+
+        if self.data.is_struct() {
+            for transition in &self.transitions {
+                errors.push(
+                    darling::Error::custom("transition is only supported on enums")
+                        .with_span(transition.to()),
+                );
+            }
+        }
+
+        if self.data.is_enum() {
+            let variants = self.variants();
+
+            for variant in &variants {
+                for field in variant.fields.iter() {
+                    if field.count.is_some() || field.exists.is_some() {
+                        errors.push(
+                            darling::Error::custom(
+                                "`count` and `exists` are only supported on struct fields",
+                            )
+                            .with_span(&field.ident),
+                        );
+                    }
+                }
+            }
+
+            for transition in &self.transitions {
+                for state in transition.states() {
+                    if !variants.iter().any(|variant| variant.ident == *state) {
+                        errors.push(
+                            darling::Error::custom("state is not a variant of this enum")
+                                .with_span(state),
+                        );
+                    }
+                }
+
+                if transition.with.is_none() {
+                    errors.handle(transition.validate_fields(&variants));
+                }
+            }
+        }
+
+        errors.finish_with(self)
+    }
 }
 
 impl Projection {
     pub fn dispatch(&self) -> TokenStream {
         let ident = &self.ident;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
         let event = self.events();
 
         let dispatch_trait = format_ident!("{ident}Dispatch");
 
+        let dispatch_candidate = self.candidate().then(|| {
+            quote! {
+                fn dispatch_candidate(
+                    &mut self,
+                    event: &::eventric_model::projection::DispatchEvent<::eventric_model::projection::Candidate>,
+                    selected: &::eventric_model::projection::Selected,
+                ) -> ::std::result::Result<(), ::eventric_model::error::Error> {
+                    match event {
+                      #(_ if let std::option::Option::Some(projection_event) = event.as_projection_event::<#event>(selected) => {
+                            ::eventric_model::projection::ProjectCandidate::project_candidate(self, projection_event)
+                                .map_err(|err| event.projection_error::<Self, _>(err))?;
+                        })*
+                        _ => {}
+                    }
+
+                    Ok(())
+                }
+            }
+        });
+
         quote! {
-            pub trait #dispatch_trait: #(::eventric_model::projection::Project<#event>)+* {}
+            pub trait #dispatch_trait #impl_generics: #(::eventric_model::projection::Project<#event>)+* #where_clause {}
 
             #[automatically_derived]
-            impl #dispatch_trait for #ident {}
+            impl #impl_generics #dispatch_trait #ty_generics for #ident #ty_generics #where_clause {}
 
             #[automatically_derived]
-            impl ::eventric_model::projection::Dispatch for #ident {
-                fn dispatch(&mut self, event: &::eventric_model::projection::DispatchEvent) {
+            impl #impl_generics ::eventric_model::projection::Dispatch for #ident #ty_generics #where_clause {
+                fn dispatch(
+                    &mut self,
+                    event: &::eventric_model::projection::DispatchEvent,
+                    selected: &::eventric_model::projection::Selected,
+                ) -> ::std::result::Result<(), ::eventric_model::error::Error> {
                     match event {
-                      #(_ if let std::option::Option::Some(event) = event.as_projection_event::<#event>() => self.project(event),)*
+                      #(_ if let std::option::Option::Some(projection_event) = event.as_projection_event::<#event>(selected) => {
+                            ::eventric_model::projection::Project::project(self, projection_event).map_err(|err| event.projection_error::<Self, _>(err))?;
+                        })*
                         _ => {}
                     }
+
+                    Ok(())
                 }
+
+                #dispatch_candidate
             }
         }
     }
 
+    fn project(&self) -> TokenStream {
+        let ident = &self.ident;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let candidate = self.candidate();
+
+        self.projected()
+            .iter()
+            .map(|event| {
+                let transition = self
+                    .transitions
+                    .iter()
+                    .filter(|transition| transition.on.as_ref().contains(event))
+                    .collect::<Vec<_>>();
+
+                let project_err = if self.fields().iter().any(|field| field.counts(event)) {
+                    Some(quote! {
+                        type Err = ::eventric_model::projection::library::Overflow;
+                    })
+                } else if !transition.is_empty() {
+                    Some(quote! {
+                        type Err = ::eventric_model::error::TransitionError;
+                    })
+                } else {
+                    None
+                };
+
+                let (project_event, project_body) = self.project_body(event, &transition, None);
+
+                let project_candidate = candidate.then(|| {
+                    let origin = quote! { ::eventric_model::projection::Candidate };
+                    let (project_event, project_body) =
+                        self.project_body(event, &transition, Some(&origin));
+
+                    quote! {
+                        #[automatically_derived]
+                        impl #impl_generics ::eventric_model::projection::ProjectCandidate<#event> for #ident #ty_generics #where_clause {
+                            fn project_candidate(
+                                &mut self,
+                                #project_event: ::eventric_model::projection::ProjectionEvent<'_, #event, #origin>
+                            ) -> ::std::result::Result<(), Self::Err> {
+                                #project_body
+                            }
+                        }
+                    }
+                });
+
+                quote! {
+                    #[automatically_derived]
+                    impl #impl_generics ::eventric_model::projection::Project<#event> for #ident #ty_generics #where_clause {
+                        #project_err
+
+                        fn project(
+                            &mut self,
+                            #project_event: ::eventric_model::projection::ProjectionEvent<'_, #event>
+                        ) -> ::std::result::Result<(), Self::Err> {
+                            #project_body
+                        }
+                    }
+
+                    #project_candidate
+                }
+            })
+            .collect()
+    }
+
+    fn project_body(
+        &self,
+        event: &Path,
+        transition: &[&Transition],
+        origin: Option<&TokenStream>,
+    ) -> (TokenStream, TokenStream) {
+        let fields = self.fields();
+        let origin = origin.map(|origin| quote! { , #origin });
+
+        let count = fields
+            .iter()
+            .filter(|field| field.counts(event))
+            .map(|field| &field.ident)
+            .collect::<Vec<_>>();
+
+        let exists = fields
+            .iter()
+            .filter(|field| field.exists(event))
+            .map(|field| &field.ident);
+
+        let handler = self
+            .handlers
+            .iter()
+            .filter(|handler| handler.event == *event)
+            .map(|handler| &handler.handler)
+            .collect::<Vec<_>>();
+
+        let project_event = if handler.is_empty()
+            && transition
+                .iter()
+                .all(|transition| transition.with.is_none())
+        {
+            quote! { _ }
+        } else {
+            quote! { event }
+        };
+
+        let project_transition = self.project_transition(event, transition, origin.as_ref());
+
+        let project_body = quote! {
+            #project_transition
+          #(self.#exists = true;)*
+          #(self.#count = self
+                .#count
+                .checked_add(1)
+                .ok_or(::eventric_model::projection::library::Overflow)?;)*
+          #(::std::convert::identity::<
+                fn(&mut Self, &::eventric_model::projection::ProjectionEvent<'_, #event #origin>)
+            >(#handler)(self, &event);)*
+
+            Ok(())
+        };
+
+        (project_event, project_body)
+    }
+
     fn projection(&self) -> TokenStream {
         let ident = &self.ident;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
 
         quote! {
-            impl ::eventric_model::projection::Projection for #ident {}
+            impl #impl_generics ::eventric_model::projection::Projection for #ident #ty_generics #where_clause {}
         }
     }
 
+    fn project_transition(
+        &self,
+        event: &Path,
+        transition: &[&Transition],
+        origin: Option<&TokenStream>,
+    ) -> Option<TokenStream> {
+        (!transition.is_empty()).then(|| {
+            let variants = self.variants();
+            let transition_arm = transition
+                .iter()
+                .map(|transition| TransitionArm(&variants, transition, event, origin));
+
+            let uncovered = variants.iter().any(|variant| {
+                !transition
+                    .iter()
+                    .any(|transition| transition.covers(&variant.ident))
+            });
+
+            let transition_fallback = uncovered.then(|| {
+                let variant = variants.iter().map(|variant| &variant.ident);
+                let variant_name = variants.iter().map(|variant| variant.ident.to_string());
+                let event_name = event.to_token_stream().to_string().replace(' ', "");
+
+                quote! {
+                    state => {
+                        return Err(::eventric_model::error::TransitionError::new(
+                            #event_name,
+                            match state {
+                              #(Self::#variant { .. } => #variant_name,)*
+                            },
+                        ));
+                    }
+                }
+            });
+
+            quote! {
+                let state = match self {
+                  #(#transition_arm,)*
+                  #transition_fallback
+                };
+
+                *self = state;
+            }
+        })
+    }
+
     fn recognize(&self) -> TokenStream {
         let ident = &self.ident;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
         let event = self.events();
 
         let recognize_match_arm = event.iter().map(RecognizeMatchArm);
+        let recognize_candidate_match_arm = event.iter().map(RecognizeCandidateMatchArm);
+        let recognize_decode = self.decode.as_ref().map(|decode| {
+            quote! {
+                const DECODE: ::eventric_model::projection::Decode = #decode;
+            }
+        });
 
         quote! {
             #[automatically_derived]
-            impl ::eventric_model::projection::Recognize for #ident {
+            impl #impl_generics ::eventric_model::projection::Recognize for #ident #ty_generics #where_clause {
+                #recognize_decode
+
                 fn recognize(
                     &self,
                     event: &::eventric_stream::stream::select::EventAndMask
@@ -117,27 +460,55 @@ impl Projection {
 
                     Ok(event)
                 }
+
+                fn recognize_candidate(
+                    &self,
+                    event: &::eventric_stream::event::CandidateEvent
+                ) -> ::std::result::Result<
+                    ::std::option::Option<
+                        ::eventric_model::projection::DispatchEvent<::eventric_model::projection::Candidate>
+                    >,
+                    ::eventric_model::error::Error
+                > {
+                    let event = match event {
+                        #(#recognize_candidate_match_arm),*
+                        _ => std::option::Option::None,
+                    };
+
+                    Ok(event)
+                }
             }
         }
     }
 
     fn select(&self) -> TokenStream {
         let ident = &self.ident;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
         let selectors = self.selectors();
 
-        let selector_initialize = selectors
-            .iter()
-            .map(|selector| SelectorInitialize(ident, selector));
+        let selector_initialize = selectors.iter().map(SelectorInitialize);
+
+        let selector_filter = selectors.iter().map(SelectorFilter);
+
+        let selector_name = selectors.iter().map(SelectorName);
 
         quote! {
             #[automatically_derived]
-            impl ::eventric_model::projection::Select for #ident {
+            impl #impl_generics ::eventric_model::projection::Select for #ident #ty_generics #where_clause {
                 fn select(&self) -> ::std::result::Result<
                     ::eventric_stream::stream::select::Selection,
                     ::eventric_stream::error::Error
                 > {
                     ::eventric_stream::stream::select::Selection::new([#(#selector_initialize?),*])
                 }
+
+                fn selectors(&self) -> ::std::result::Result<
+                    ::eventric_model::projection::Selectors,
+                    ::eventric_stream::error::Error
+                > {
+                    Ok(::eventric_model::projection::Selectors::new(&[#(#selector_name),*])
+                      #(#selector_filter)*)
+                }
             }
         }
     }
@@ -147,6 +518,7 @@ impl ToTokens for Projection {
     #[rustfmt::skip]
     fn to_tokens(&self, tokens: &mut TokenStream) {
         tokens.append_all(self.projection());
+        tokens.append_all(self.project());
         tokens.append_all(self.dispatch());
         tokens.append_all(self.recognize());
         tokens.append_all(self.select());
@@ -155,6 +527,268 @@ impl ToTokens for Projection {
 
 // -------------------------------------------------------------------------------------------------
 
+// Field
+
+#[derive(Debug, FromField)]
+#[darling(attributes(projection))]
+pub struct Field {
+    ident: Option<Ident>,
+    count: Option<List<Path>>,
+    exists: Option<List<Path>>,
+}
+
+impl Field {
+    fn counts(&self, event: &Path) -> bool {
+        self.count
+            .as_ref()
+            .is_some_and(|count| count.as_ref().contains(event))
+    }
+
+    fn exists(&self, event: &Path) -> bool {
+        self.exists
+            .as_ref()
+            .is_some_and(|exists| exists.as_ref().contains(event))
+    }
+}
+
+// Variant
+
+#[derive(Debug, FromVariant)]
+#[darling(attributes(projection))]
+pub struct Variant {
+    ident: Ident,
+    fields: Fields<Field>,
+}
+
+// On
+
+#[derive(Debug)]
+pub struct On {
+    pub event: Path,
+    pub handler: ExprClosure,
+}
+
+impl FromMeta for On {
+    fn from_meta(meta: &Meta) -> darling::Result<Self> {
+        let list = meta.require_list()?;
+        let input = list.tokens.clone();
+
+        syn::parse2(input).map_err(darling::Error::custom)
+    }
+}
+
+impl Parse for On {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let event = Path::parse(input)?;
+        let _ = FatArrow::parse(input)?;
+        let handler = ExprClosure::parse(input)?;
+
+        Ok(Self { event, handler })
+    }
+}
+
+// Transition
+
+#[derive(Debug, FromMeta)]
+#[darling(and_then = Self::validate)]
+pub struct Transition {
+    pub from: Option<List<Ident>>,
+    pub on: List<Path>,
+    pub to: List<Ident>,
+    pub with: Option<Expr>,
+}
+
+impl Transition {
+    fn covers(&self, variant: &Ident) -> bool {
+        self.from
+            .as_ref()
+            .is_none_or(|from| from.as_ref().contains(variant))
+    }
+
+    fn states(&self) -> impl Iterator<Item = &Ident> {
+        self.from
+            .iter()
+            .flat_map(AsRef::as_ref)
+            .chain(self.to.as_ref())
+    }
+
+    fn to(&self) -> &Ident {
+        &self.to.as_ref()[0]
+    }
+
+    fn validate_fields(&self, variants: &[&Variant]) -> darling::Result<()> {
+        let mut errors = darling::Error::accumulator();
+
+        let field_names = |state: &Ident| {
+            variants
+                .iter()
+                .find(|variant| variant.ident == *state)
+                .map(|variant| {
+                    variant
+                        .fields
+                        .iter()
+                        .filter_map(|field| field.ident.clone())
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_default()
+        };
+
+        let to = self.to();
+        let to_field = field_names(to);
+
+        for variant in variants
+            .iter()
+            .filter(|variant| self.covers(&variant.ident))
+        {
+            let from_field = field_names(&variant.ident);
+
+            for field in to_field.iter().filter(|field| !from_field.contains(field)) {
+                errors.push(
+                    darling::Error::custom(format!(
+                        "state `{}` has no field `{field}` to carry into `{to}`, use `with` to \
+                         build the target state",
+                        variant.ident
+                    ))
+                    .with_span(to),
+                );
+            }
+        }
+
+        errors.finish()
+    }
+
+    fn validate(self) -> darling::Result<Self> {
+        if self.on.as_ref().is_empty() {
+            return Err(darling::Error::custom(
+                "transition requires at least one event in `on`",
+            ));
+        }
+
+        if self.to.as_ref().len() != 1 {
+            return Err(darling::Error::custom(
+                "transition requires exactly one state in `to`",
+            ));
+        }
+
+        Ok(self)
+    }
+}
+
+// Transition Composites
+
+pub struct TransitionArm<'a>(
+    &'a [&'a Variant],
+    &'a Transition,
+    &'a Path,
+    Option<&'a TokenStream>,
+);
+
+impl ToTokens for TransitionArm<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let TransitionArm(variants, transition, event, origin) = *self;
+
+        let from = match &transition.from {
+            Some(from) => from.as_ref().iter().collect::<Vec<_>>(),
+            None => variants.iter().map(|variant| &variant.ident).collect(),
+        };
+
+        if let Some(with) = &transition.with {
+            tokens.append_all(quote! {
+                #(Self::#from { .. })|* => ::std::convert::identity::<
+                    fn(&Self, &::eventric_model::projection::ProjectionEvent<'_, #event #origin>) -> Self
+                >(#with)(self, &event)
+            });
+
+            return;
+        }
+
+        let to = transition.to();
+        let to_field = variants
+            .iter()
+            .find(|variant| variant.ident == *to)
+            .map(|variant| {
+                variant
+                    .fields
+                    .iter()
+                    .filter_map(|field| field.ident.as_ref())
+            })
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+
+        let from_pattern = from.iter().map(|from| {
+            quote! {
+                Self::#from { #(#to_field,)* .. }
+            }
+        });
+
+        tokens.append_all(quote! {
+            #(#from_pattern)|* => Self::#to {
+              #(#to_field: ::std::mem::take(#to_field),)*
+            }
+        });
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Decode
+
+#[derive(Debug)]
+pub enum Decode {
+    DeadLetter(Path),
+    Fail,
+    Skip,
+}
+
+impl FromMeta for Decode {
+    fn from_meta(meta: &Meta) -> darling::Result<Self> {
+        let list = meta.require_list()?;
+        let input = list.tokens.clone();
+
+        syn::parse2(input).map_err(darling::Error::custom)
+    }
+}
+
+impl Parse for Decode {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let ident = Ident::parse(input)?;
+
+        match ident.to_string().as_str() {
+            "dead_letter" => {
+                let content;
+                let _ = parenthesized!(content in input);
+
+                Path::parse(&content).map(Self::DeadLetter)
+            }
+            "fail" => Ok(Self::Fail),
+            "skip" => Ok(Self::Skip),
+            _ => Err(syn::Error::new(
+                ident.span(),
+                "expected one of `dead_letter`, `fail` or `skip`",
+            )),
+        }
+    }
+}
+
+impl ToTokens for Decode {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Self::DeadLetter(path) => tokens.append_all(quote! {
+                ::eventric_model::projection::Decode::DeadLetter(#path)
+            }),
+            Self::Fail => tokens.append_all(quote! {
+                ::eventric_model::projection::Decode::Fail
+            }),
+            Self::Skip => tokens.append_all(quote! {
+                ::eventric_model::projection::Decode::Skip
+            }),
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
 // Recognize
 
 pub struct RecognizeMatchArm<'a>(&'a Path);
@@ -164,10 +798,28 @@ impl ToTokens for RecognizeMatchArm<'_> {
         let RecognizeMatchArm(event) = *self;
 
         tokens.append_all(quote! {
-            _ if event.event.identifier() == <#event as ::eventric_model::event::Identifier>::identifier()? => {
-                std::option::Option::Some(
-                    ::eventric_model::projection::DispatchEvent::from_event::<#event>(&event.event)?
-                )
+            _ if <#event as ::eventric_model::event::Identifiers>::identifiers()?.contains(&event.event.identifier()) => {
+                match ::eventric_model::projection::DispatchEvent::from_event::<#event>(&event.event) {
+                    Ok(dispatch_event) => std::option::Option::Some(dispatch_event),
+                    Err(err) => <Self as ::eventric_model::projection::Recognize>::DECODE.recover(::eventric_model::projection::Undecoded::Stored(&event.event), err)?,
+                }
+            }
+        });
+    }
+}
+
+pub struct RecognizeCandidateMatchArm<'a>(&'a Path);
+
+impl ToTokens for RecognizeCandidateMatchArm<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let RecognizeCandidateMatchArm(event) = *self;
+
+        tokens.append_all(quote! {
+            _ if <#event as ::eventric_model::event::Identifiers>::identifiers()?.contains(&event.identifier()) => {
+                match ::eventric_model::projection::DispatchEvent::from_candidate::<#event>(event) {
+                    Ok(dispatch_event) => std::option::Option::Some(dispatch_event),
+                    Err(err) => <Self as ::eventric_model::projection::Recognize>::DECODE.recover(::eventric_model::projection::Undecoded::Candidate(event), err)?,
+                }
             }
         });
     }
@@ -178,36 +830,216 @@ impl ToTokens for RecognizeMatchArm<'_> {
 // Select
 
 #[derive(Debug, FromMeta)]
+#[darling(and_then = Self::validate)]
 pub struct Selector {
+    #[darling(default)]
     pub events: List<Path>,
     #[darling(map = "event::tags_map")]
     pub filter: Option<HashMap<Ident, List<event::Tag>>>,
+    pub name: Option<Ident>,
+    #[darling(default)]
+    pub variants: List<Path>,
+}
+
+impl Selector {
+    fn validate(self) -> darling::Result<Self> {
+        if self.events.as_ref().is_empty() && self.variants.as_ref().is_empty() {
+            return Err(darling::Error::custom(
+                "select requires at least one of `events` or `variants`",
+            ));
+        }
+
+        let mut errors = darling::Error::accumulator();
+
+        for variant in self.variants.as_ref() {
+            if variant.segments.len() < 2 {
+                errors.push(
+                    darling::Error::custom(
+                        "variant must be a path to an enum variant, such as `Event::Variant`",
+                    )
+                    .with_span(variant),
+                );
+            }
+        }
+
+        errors.finish_with(self)
+    }
 }
 
 // Selector Composites
 
-pub struct SelectorInitialize<'a>(pub &'a Ident, pub &'a Selector);
+pub struct SelectorInitialize<'a>(pub &'a Selector);
 
 impl ToTokens for SelectorInitialize<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        let SelectorInitialize(ident, selector) = *self;
+        let SelectorInitialize(selector) = *self;
 
-        let event = selector.events.as_ref();
-        let tag = event::tags_fold(ident, selector.filter.as_ref());
+        let identifiers = SelectorIdentifiers(selector);
+        let tag = event::tags_fold(selector.filter.as_ref());
+
+        let specifiers = quote! {
+            #identifiers
+                .into_iter()
+                .cloned()
+                .map(::eventric_stream::event::Specifier::new)
+                .collect::<::std::vec::Vec<_>>()
+        };
 
         if tag.is_empty() {
             tokens.append_all(quote! {
-                ::eventric_stream::stream::select::Selector::specifiers(
-                    [#(<#event as ::eventric_model::event::Specifier>::specifier()?),*]
-                )
+                ::eventric_stream::stream::select::Selector::specifiers(#specifiers)
             });
         } else {
             tokens.append_all(quote! {
                 ::eventric_stream::stream::select::Selector::specifiers_and_tags(
-                    [#(<#event as ::eventric_model::event::Specifier>::specifier()?),*],
+                    #specifiers,
                     [#(#tag?),*]
                 )
             });
         }
     }
 }
+
+pub struct SelectorFilter<'a>(pub &'a Selector);
+
+impl ToTokens for SelectorFilter<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let SelectorFilter(selector) = *self;
+
+        let identifiers = SelectorIdentifiers(selector);
+        let tag = event::tags_fold(selector.filter.as_ref());
+
+        tokens.append_all(quote! {
+            .selector(#identifiers, [#(#tag?),*])
+        });
+    }
+}
+
+pub struct SelectorIdentifiers<'a>(pub &'a Selector);
+
+impl ToTokens for SelectorIdentifiers<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let SelectorIdentifiers(selector) = *self;
+
+        let event = selector.events.as_ref();
+        let (variant_type, variant_name): (Vec<_>, Vec<_>) = selector
+            .variants
+            .as_ref()
+            .iter()
+            .map(variant_split)
+            .map(|(variant_type, variant)| (variant_type, variant.to_string()))
+            .unzip();
+        let variant = selector.variants.as_ref();
+
+        tokens.append_all(quote! {{
+            let mut identifiers: ::std::vec::Vec<&'static ::eventric_stream::event::Identifier> =
+                ::std::vec::Vec::new();
+
+          #(identifiers.extend(<#event as ::eventric_model::event::Identifiers>::identifiers()?);)*
+          #(identifiers.push({
+                let _ = |event: &#variant_type| ::std::matches!(event, #variant { .. });
+
+                <#variant_type as ::eventric_model::event::Variants>::variant(#variant_name)?
+            });)*
+
+            identifiers
+        }});
+    }
+}
+
+pub struct SelectorName<'a>(pub &'a Selector);
+
+impl ToTokens for SelectorName<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let SelectorName(selector) = *self;
+
+        match &selector.name {
+            Some(name) => {
+                let name = name.to_string();
+
+                tokens.append_all(quote! { ::std::option::Option::Some(#name) });
+            }
+            None => tokens.append_all(quote! { ::std::option::Option::None }),
+        }
+    }
+}
+
+// Functions
+
+pub fn variant_split(variant: &Path) -> (Path, Ident) {
+    let count = variant.segments.len().saturating_sub(1);
+    let segments = variant.segments.iter().take(count).cloned().collect();
+    let ident = variant.segments.last().expect("variant").ident.clone();
+
+    let path = Path {
+        leading_colon: variant.leading_colon,
+        segments,
+    };
+
+    (path, ident)
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use quote::ToTokens as _;
+    use syn::parse_quote;
+
+    use super::Projection;
+
+    fn expand(input: &syn::DeriveInput) -> Result<String, String> {
+        Projection::new(input)
+            .map(|projection| projection.into_token_stream().to_string())
+            .map_err(|err| err.to_string())
+    }
+
+    #[test]
+    fn transitions_without_with_require_target_fields() {
+        let error = expand(&parse_quote! {
+            #[projection(
+                select(events(CourseOpened)),
+                transition(from(Draft), on(CourseOpened), to(Open))
+            )]
+            enum Lifecycle {
+                Draft { course: String },
+                Open { course: String, seats: u64 },
+            }
+        })
+        .unwrap_err();
+
+        assert!(error.contains("state `Draft` has no field `seats` to carry into `Open`"));
+
+        let expanded = expand(&parse_quote! {
+            #[projection(
+                select(events(CourseClosed)),
+                transition(from(Open), on(CourseClosed), to(Closed))
+            )]
+            enum Lifecycle {
+                Open { course: String, seats: u64 },
+                Closed { course: String },
+            }
+        })
+        .unwrap();
+
+        assert!(expanded.contains("course : :: std :: mem :: take (course)"));
+    }
+
+    #[test]
+    fn variant_fields_cannot_count_or_exist() {
+        let error = expand(&parse_quote! {
+            #[projection(select(events(CourseOpened)))]
+            enum Lifecycle {
+                Open {
+                    #[projection(count(CourseOpened))]
+                    opened: u64,
+                },
+            }
+        })
+        .unwrap_err();
+
+        assert!(error.contains("only supported on struct fields"));
+    }
+}
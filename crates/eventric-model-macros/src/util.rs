@@ -18,6 +18,15 @@ pub struct List<T>(Vec<T>)
 where
     T: Parse;
 
+impl<T> Default for List<T>
+where
+    T: Parse,
+{
+    fn default() -> Self {
+        Self(Vec::new())
+    }
+}
+
 impl<T> AsRef<Vec<T>> for List<T>
 where
     T: Parse,
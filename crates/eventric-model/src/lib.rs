@@ -8,35 +8,152 @@ pub mod action {
     pub use eventric_model_core::action::{
         Act,
         Action,
+        AnyAction,
         Context,
+        Decide,
+        Invariant,
+        InvariantKind,
+        Invariants,
         Select,
         Update,
     };
-    pub use eventric_model_macros::Action;
+    pub use eventric_model_macros::{
+        Action,
+        Context,
+    };
+
+    pub mod composite {
+        pub use eventric_model_core::action::composite::{
+            Composite,
+            CompositeContext,
+            CompositeDeps,
+        };
+    }
+}
+
+pub mod bus {
+    pub use eventric_model_core::bus::{
+        CommandBus,
+        Reply,
+    };
+}
+
+pub mod error {
+    pub use eventric_model_core::error::{
+        BusError,
+        CheckpointError,
+        CompositeError,
+        Error,
+        InitializeError,
+        OutboxError,
+        ProjectionError,
+        ReactionError,
+        RegistryError,
+        ScheduleError,
+        TransitionError,
+    };
 }
 
 pub mod event {
     pub use eventric_model_core::event::{
+        AnyEvent,
         Event,
         Events,
         Identifier,
+        Identifiers,
+        Identify,
         Specifier,
+        Specifiers,
         Tags,
+        Variants,
+        identifier,
     };
     pub use eventric_model_macros::Event;
 }
 
+pub mod outbox {
+    pub use eventric_model_core::outbox::{
+        ChannelSink,
+        FileSink,
+        OutboxMessage,
+        Relay,
+        Sink,
+    };
+}
+
+pub mod process {
+    pub use eventric_model_core::process::{
+        Checkpoint,
+        FileCheckpoint,
+        MemoryCheckpoint,
+        Process,
+        ProcessManager,
+    };
+}
+
 pub mod projection {
     pub use eventric_model_core::projection::{
+        Candidate,
+        Decode,
         Dispatch,
         DispatchEvent,
+        Origin,
         Project,
+        ProjectCandidate,
         Projection,
         ProjectionEvent,
         Recognize,
         Select,
+        Selected,
+        Selectors,
+        Stored,
+        Undecoded,
     };
     pub use eventric_model_macros::Projection;
+
+    pub mod library {
+        pub use eventric_model_core::projection::library::{
+            Count,
+            DistinctTags,
+            Exists,
+            Keyed,
+            Last,
+            Overflow,
+            Sum,
+            Summable,
+        };
+    }
+}
+
+pub mod reaction {
+    pub use eventric_model_core::reaction::{
+        Reacting,
+        Reaction,
+    };
+}
+
+pub mod schedule {
+    pub use eventric_model_core::schedule::{
+        CancelTimer,
+        Clock,
+        Deadline,
+        Deadlines,
+        FireTimer,
+        ManualClock,
+        ScheduleTimer,
+        Scheduler,
+        SystemClock,
+        TimerCancelled,
+        TimerContext,
+        TimerFired,
+        TimerScheduled,
+    };
 }
 
-pub use eventric_model_core::core::Enactor;
+pub use eventric_model_core::core::{
+    DynEnactor,
+    DynEnactorExt,
+    Enactor,
+    RecordingEnactor,
+    Registry,
+};
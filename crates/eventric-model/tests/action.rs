@@ -0,0 +1,226 @@
+#![feature(associated_type_defaults)]
+#![feature(if_let_guard)]
+#![feature(once_cell_try)]
+
+use eventric_model::{
+    Enactor as _,
+    action::{
+        Act,
+        Action,
+    },
+    event::Event,
+    projection::Projection,
+};
+use eventric_stream::stream::Stream;
+use fancy_constructor::new;
+use revision::revisioned;
+use tempfile::TempDir;
+use thiserror::Error;
+
+// =================================================================================================
+// Action
+// =================================================================================================
+
+// Events
+
+#[revisioned(revision = 1)]
+#[derive(new, Event, Debug)]
+#[event(identifier(student_enrolled), tags(student(&this.student)))]
+pub struct StudentEnrolled {
+    #[new(into)]
+    pub student: String,
+}
+
+#[revisioned(revision = 1)]
+#[derive(new, Event, Debug)]
+#[event(identifier(voucher_redeemed), tags(voucher(&this.voucher)))]
+pub struct VoucherRedeemed {
+    #[new(into)]
+    pub voucher: String,
+}
+
+// Projections
+
+#[derive(new, Projection, Debug)]
+#[projection(
+    select(
+        events(StudentEnrolled),
+        filter(student(&this.student))
+    )
+)]
+pub struct Enrolled {
+    #[new(into)]
+    pub student: String,
+    #[new(default)]
+    #[projection(exists(StudentEnrolled))]
+    pub exists: bool,
+}
+
+#[derive(new, Projection, Debug)]
+#[projection(
+    select(
+        events(VoucherRedeemed),
+        filter(voucher(&this.voucher))
+    )
+)]
+pub struct Redeemed {
+    #[new(into)]
+    pub voucher: String,
+    #[new(default)]
+    #[projection(exists(VoucherRedeemed))]
+    pub exists: bool,
+}
+
+// Actions
+
+#[derive(new, Action, Debug)]
+#[action(
+    projection(
+        many Vec<Enrolled> @ enrolments:
+            |this| this.students.iter().map(Enrolled::new).collect()
+    ),
+    projection(
+        optional Option<Redeemed> @ redemption:
+            |this| this.voucher.as_ref().map(Redeemed::new)
+    )
+)]
+pub struct EnrolAll {
+    pub students: Vec<String>,
+    pub voucher: Option<String>,
+}
+
+impl Act for EnrolAll {
+    type Err = ActionError;
+    type Ok = Vec<String>;
+
+    fn action(&mut self, context: &mut Self::Context) -> Result<Self::Ok, Self::Err> {
+        if context
+            .redemption
+            .as_ref()
+            .is_some_and(|redeemed| redeemed.exists)
+        {
+            return Err(ActionError::Redeemed);
+        }
+
+        let enrolled = context
+            .enrolments
+            .iter()
+            .filter(|enrolled| enrolled.exists)
+            .map(|enrolled| enrolled.student.clone())
+            .collect();
+
+        for enrolment in context
+            .enrolments
+            .iter()
+            .filter(|enrolled| !enrolled.exists)
+        {
+            context
+                .events
+                .append(&StudentEnrolled::new(&enrolment.student))?;
+        }
+
+        if let Some(voucher) = &self.voucher {
+            context.append(&VoucherRedeemed::new(voucher))?;
+        }
+
+        Ok(enrolled)
+    }
+}
+
+// Errors
+
+#[derive(Debug, Error)]
+pub enum ActionError {
+    #[error("Voucher Redeemed")]
+    Redeemed,
+    #[error(transparent)]
+    Model(#[from] eventric_model::error::Error),
+    #[error(transparent)]
+    Stream(#[from] eventric_stream::error::Error),
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Tests
+
+#[test]
+fn many_projections_select_one_state_per_element() {
+    let (_dir, mut stream) = stream();
+
+    stream
+        .enact(EnrolAll::new(students(["ann", "bob"]), None))
+        .unwrap();
+
+    let enrolled = stream
+        .enact(EnrolAll::new(students(["cat", "bob", "dan", "ann"]), None))
+        .unwrap();
+
+    assert_eq!(enrolled, ["bob", "ann"]);
+}
+
+#[test]
+fn many_projections_may_be_empty() {
+    let (_dir, mut stream) = stream();
+
+    assert_eq!(
+        stream.enact(EnrolAll::new(Vec::new(), None)).unwrap(),
+        Vec::<String>::new()
+    );
+}
+
+#[test]
+fn optional_projections_are_selected_when_present() {
+    let (_dir, mut stream) = stream();
+
+    let voucher = Some(String::from("spring"));
+
+    stream
+        .enact(EnrolAll::new(students(["ann"]), voucher.clone()))
+        .unwrap();
+
+    assert!(matches!(
+        stream.enact(EnrolAll::new(students(["bob"]), voucher)),
+        Err(ActionError::Redeemed)
+    ));
+}
+
+#[test]
+fn optional_projections_keep_later_selections_aligned() {
+    let (_dir, mut stream) = stream();
+
+    stream
+        .enact(EnrolAll::new(
+            students(["ann"]),
+            Some(String::from("spring")),
+        ))
+        .unwrap();
+
+    let enrolled = stream
+        .enact(EnrolAll::new(students(["ann", "bob"]), None))
+        .unwrap();
+
+    assert_eq!(enrolled, ["ann"]);
+    assert!(
+        stream
+            .enact(EnrolAll::new(
+                students(["cat"]),
+                Some(String::from("autumn"))
+            ))
+            .is_ok()
+    );
+}
+
+// Helpers
+
+fn students<const N: usize>(students: [&str; N]) -> Vec<String> {
+    students.into_iter().map(String::from).collect()
+}
+
+fn stream() -> (TempDir, Stream) {
+    let dir = TempDir::new().expect("temp dir");
+    let stream = Stream::builder(&dir.path().to_string_lossy())
+        .open()
+        .expect("stream");
+
+    (dir, stream)
+}
@@ -0,0 +1,288 @@
+#![feature(associated_type_defaults)]
+#![feature(if_let_guard)]
+#![feature(once_cell_try)]
+
+use eventric_model::{
+    Enactor,
+    action::{
+        Act,
+        Action,
+    },
+    error::Error,
+    event::Event,
+    projection::{
+        Candidate,
+        Project,
+        ProjectCandidate,
+        Projection,
+        ProjectionEvent,
+    },
+};
+use eventric_stream::{
+    event::{
+        CandidateEvent,
+        Position,
+    },
+    stream::{
+        Select,
+        Stream,
+        append::{
+            Append,
+            AppendSelect,
+        },
+        iterate::IterateSelect,
+        select::{
+            EventAndMask,
+            Selections,
+        },
+    },
+};
+use fancy_constructor::new;
+use revision::revisioned;
+use tempfile::TempDir;
+use thiserror::Error;
+
+// =================================================================================================
+// Batch
+// =================================================================================================
+
+// Events
+
+#[revisioned(revision = 1)]
+#[derive(new, Event, Debug)]
+#[event(
+    identifier(resource_reserved),
+    tags(resource(&this.resource))
+)]
+pub struct ResourceReserved {
+    #[new(into)]
+    pub resource: String,
+    #[new(into)]
+    pub holder: String,
+}
+
+// Projections
+
+#[derive(new, Projection, Debug)]
+#[projection(
+    candidate,
+    select(
+        events(ResourceReserved),
+        filter(resource(&this.resource))
+    )
+)]
+pub struct Reservations {
+    #[new(into)]
+    pub resource: String,
+    #[new(default)]
+    pub count: u8,
+}
+
+impl Reservations {
+    fn try_new(resource: &str) -> Result<Self, ReservationError> {
+        match resource {
+            "" => Err(ReservationError::Unnamed),
+            resource => Ok(Self::new(resource)),
+        }
+    }
+}
+
+impl Project<ResourceReserved> for Reservations {
+    fn project(&mut self, _: ProjectionEvent<'_, ResourceReserved>) -> Result<(), Self::Err> {
+        self.count += 1;
+
+        Ok(())
+    }
+}
+
+impl ProjectCandidate<ResourceReserved> for Reservations {
+    fn project_candidate(
+        &mut self,
+        _: ProjectionEvent<'_, ResourceReserved, Candidate>,
+    ) -> Result<(), Self::Err> {
+        self.count += 1;
+
+        Ok(())
+    }
+}
+
+// Actions
+
+#[derive(new, Action, Debug)]
+#[action(
+    projection(Reservations: ?|this| Reservations::try_new(&this.resource)),
+    forbid(
+        |_, context| context.reservations.count >= 2,
+        ReservationError::Full
+    )
+)]
+pub struct Reserve {
+    #[new(into)]
+    pub resource: String,
+    #[new(into)]
+    pub holder: String,
+}
+
+impl Act for Reserve {
+    type Err = ReservationError;
+
+    fn action(&mut self, context: &mut Self::Context) -> Result<Self::Ok, Self::Err> {
+        context.append(&ResourceReserved::new(&self.resource, &self.holder))?;
+
+        Ok(())
+    }
+}
+
+// Errors
+
+#[derive(Debug, Error)]
+pub enum ReservationError {
+    #[error("Resource Fully Reserved")]
+    Full,
+    #[error("Resource Unnamed")]
+    Unnamed,
+    #[error(transparent)]
+    Model(#[from] Error),
+    #[error(transparent)]
+    Stream(#[from] eventric_stream::error::Error),
+}
+
+// Streams
+
+#[derive(new, Debug)]
+pub struct Interleaved {
+    pub stream: Stream,
+    pub interleave: Option<Reserve>,
+}
+
+impl Append for Interleaved {
+    fn append<E>(
+        &mut self,
+        events: E,
+        after: Option<Position>,
+    ) -> Result<Position, eventric_stream::error::Error>
+    where
+        E: IntoIterator<Item = CandidateEvent>,
+    {
+        self.stream.append(events, after)
+    }
+}
+
+impl AppendSelect for Interleaved {
+    fn append_select<E>(
+        &mut self,
+        events: E,
+        select: Select,
+        after: Option<Position>,
+    ) -> Result<Position, eventric_stream::error::Error>
+    where
+        E: IntoIterator<Item = CandidateEvent>,
+    {
+        if let Some(action) = self.interleave.take() {
+            self.stream.enact(action).expect("interleaved");
+        }
+
+        self.stream.append_select(events, select, after)
+    }
+}
+
+impl IterateSelect for Interleaved {
+    fn iter_select(
+        &self,
+        selections: Selections,
+        from: Option<Position>,
+    ) -> (
+        impl Iterator<Item = Result<EventAndMask, eventric_stream::error::Error>>,
+        Select,
+    ) {
+        self.stream.iter_select(selections, from)
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Tests
+
+#[test]
+fn disjoint_members_are_accepted() {
+    let (_dir, mut stream) = stream();
+
+    let results = stream.enact_batch([Reserve::new("a", "x"), Reserve::new("b", "y")]);
+
+    assert!(matches!(results.as_slice(), [Ok(()), Ok(())]));
+}
+
+#[test]
+fn overlapping_members_are_accepted() {
+    let (_dir, mut stream) = stream();
+
+    let results = stream.enact_batch([Reserve::new("a", "x"), Reserve::new("a", "y")]);
+
+    assert!(matches!(results.as_slice(), [Ok(()), Ok(())]));
+}
+
+#[test]
+fn conflicting_members_see_earlier_members() {
+    let (_dir, mut stream) = stream();
+
+    let results = stream.enact_batch([
+        Reserve::new("a", "x"),
+        Reserve::new("a", "y"),
+        Reserve::new("a", "z"),
+        Reserve::new("b", "z"),
+    ]);
+
+    assert!(matches!(results.as_slice(), [
+        Ok(()),
+        Ok(()),
+        Err(ReservationError::Full),
+        Ok(())
+    ]));
+}
+
+#[test]
+fn failing_members_do_not_fail_the_group() {
+    let (_dir, mut stream) = stream();
+
+    let results = stream.enact_batch([
+        Reserve::new("a", "x"),
+        Reserve::new("", "y"),
+        Reserve::new("a", "z"),
+    ]);
+
+    assert!(matches!(results.as_slice(), [
+        Ok(()),
+        Err(ReservationError::Model(Error::Initialize(_))),
+        Ok(())
+    ]));
+}
+
+#[test]
+fn append_conflicts_fall_back_to_sequential_enactment() {
+    let (_dir, stream) = stream();
+
+    let mut stream = Interleaved::new(stream, Some(Reserve::new("a", "w")));
+
+    let results = stream.enact_batch([
+        Reserve::new("a", "x"),
+        Reserve::new("a", "y"),
+        Reserve::new("b", "z"),
+    ]);
+
+    assert!(matches!(results.as_slice(), [
+        Ok(()),
+        Err(ReservationError::Full),
+        Ok(())
+    ]));
+}
+
+// Helpers
+
+fn stream() -> (TempDir, Stream) {
+    let dir = TempDir::new().expect("temp dir");
+    let stream = Stream::builder(&dir.path().to_string_lossy())
+        .open()
+        .expect("stream");
+
+    (dir, stream)
+}
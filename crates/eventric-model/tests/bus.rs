@@ -0,0 +1,233 @@
+#![feature(associated_type_defaults)]
+#![feature(if_let_guard)]
+#![feature(once_cell_try)]
+
+use std::{
+    sync::{
+        Arc,
+        mpsc::{
+            self,
+            Receiver,
+        },
+    },
+    thread,
+};
+
+use eventric_model::{
+    action::{
+        Act,
+        Action,
+    },
+    bus::CommandBus,
+    error::{
+        BusError,
+        Error,
+    },
+    event::Event,
+    projection::Projection,
+};
+use eventric_stream::stream::Stream;
+use fancy_constructor::new;
+use revision::revisioned;
+use tempfile::TempDir;
+use thiserror::Error;
+
+// =================================================================================================
+// Bus
+// =================================================================================================
+
+// Events
+
+#[revisioned(revision = 1)]
+#[derive(new, Event, Debug)]
+#[event(identifier(ticket_sold), tags(show(&this.show)))]
+pub struct TicketSold {
+    #[new(into)]
+    pub show: String,
+}
+
+// Projections
+
+#[derive(new, Projection, Debug)]
+#[projection(
+    select(
+        events(TicketSold),
+        filter(show(&this.show))
+    )
+)]
+pub struct Sales {
+    #[new(into)]
+    pub show: String,
+    #[new(default)]
+    #[projection(count(TicketSold))]
+    pub count: u64,
+}
+
+// Actions
+
+#[derive(new, Action, Debug)]
+#[action(
+    projection(Sales: Sales::new(&this.show)),
+    forbid(|_, context| context.sales.count >= 100, BusTestError::SoldOut)
+)]
+pub struct Sell {
+    #[new(into)]
+    pub show: String,
+}
+
+impl Act for Sell {
+    type Err = BusTestError;
+    type Ok = u64;
+
+    fn action(&mut self, context: &mut Self::Context) -> Result<Self::Ok, Self::Err> {
+        context.append(&TicketSold::new(&self.show))?;
+
+        Ok(context.sales.count + 1)
+    }
+}
+
+#[derive(new, Action, Debug)]
+pub struct Wait {
+    pub gate: Receiver<()>,
+}
+
+impl Act for Wait {
+    type Err = BusTestError;
+
+    fn action(&mut self, _: &mut Self::Context) -> Result<Self::Ok, Self::Err> {
+        self.gate.recv().ok();
+
+        Ok(())
+    }
+}
+
+#[derive(new, Action, Debug)]
+pub struct Panic {
+    #[new(into)]
+    pub message: String,
+}
+
+impl Act for Panic {
+    type Err = BusTestError;
+
+    fn action(&mut self, _: &mut Self::Context) -> Result<Self::Ok, Self::Err> {
+        panic!("{}", self.message);
+    }
+}
+
+// Errors
+
+#[derive(Debug, Error)]
+pub enum BusTestError {
+    #[error("Sold Out")]
+    SoldOut,
+    #[error(transparent)]
+    Model(#[from] Error),
+    #[error(transparent)]
+    Stream(#[from] eventric_stream::error::Error),
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Tests
+
+#[test]
+fn actions_are_enacted_from_many_threads() {
+    let (_dir, stream) = stream();
+
+    let bus = Arc::new(CommandBus::new(stream, 8));
+
+    let workers = (0..4)
+        .map(|_| {
+            let bus = Arc::clone(&bus);
+
+            thread::spawn(move || {
+                (0..25)
+                    .map(|_| bus.enact(Sell::new("matinee")).unwrap())
+                    .collect::<Vec<_>>()
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let mut counts = workers
+        .into_iter()
+        .flat_map(|worker| worker.join().unwrap())
+        .collect::<Vec<_>>();
+
+    counts.sort_unstable();
+
+    assert_eq!(counts, (1..=100).collect::<Vec<_>>());
+    assert!(matches!(
+        bus.enact(Sell::new("matinee")),
+        Err(BusTestError::SoldOut)
+    ));
+}
+
+#[test]
+fn submitted_actions_reply_when_enacted() {
+    let (_dir, stream) = stream();
+
+    let bus = CommandBus::new(stream, 1);
+    let (open, gate) = mpsc::channel();
+
+    let waiting = bus.submit(Wait::new(gate)).unwrap();
+    let selling = bus.submit(Sell::new("evening")).unwrap();
+
+    assert!(waiting.try_wait().is_none());
+
+    open.send(()).unwrap();
+
+    assert!(waiting.wait().is_ok());
+    assert_eq!(selling.wait().unwrap(), 1);
+}
+
+#[test]
+fn full_queues_reject_submissions() {
+    let (_dir, stream) = stream();
+
+    let bus = CommandBus::new(stream, 1);
+    let (open, gate) = mpsc::channel();
+
+    let waiting = bus.submit(Wait::new(gate)).unwrap();
+
+    let mut queued = Vec::new();
+
+    let full = loop {
+        match bus.try_submit(Sell::new("evening")) {
+            Ok(reply) => queued.push(reply),
+            Err(err) => break err,
+        }
+    };
+
+    assert!(matches!(full, BusError::Full));
+    assert!(queued.len() <= 1);
+
+    open.send(()).unwrap();
+
+    assert!(waiting.wait().is_ok());
+    assert!(queued.into_iter().all(|reply| reply.wait().is_ok()));
+}
+
+#[test]
+fn panicking_actions_are_reported() {
+    let (_dir, stream) = stream();
+
+    let bus = CommandBus::new(stream, 1);
+
+    assert!(matches!(
+        bus.enact(Panic::new("boom")),
+        Err(BusTestError::Model(Error::Bus(BusError::Panicked(message)))) if message == "boom"
+    ));
+    assert_eq!(bus.enact(Sell::new("evening")).unwrap(), 1);
+}
+
+// Helpers
+
+fn stream() -> (TempDir, Stream) {
+    let dir = TempDir::new().expect("temp dir");
+    let stream = Stream::builder(&dir.path().to_string_lossy())
+        .open()
+        .expect("stream");
+
+    (dir, stream)
+}
@@ -0,0 +1,281 @@
+#![feature(associated_type_defaults)]
+#![feature(if_let_guard)]
+#![feature(once_cell_try)]
+
+use eventric_model::{
+    DynEnactorExt as _,
+    Enactor as _,
+    Registry,
+    action::{
+        Act,
+        Action,
+        Invariant,
+        Invariants,
+        composite::Composite,
+    },
+    bus::CommandBus,
+    error::CompositeError,
+    event::Event,
+    projection::Projection,
+};
+use eventric_stream::stream::Stream;
+use fancy_constructor::new;
+use revision::revisioned;
+use tempfile::TempDir;
+use thiserror::Error;
+
+// =================================================================================================
+// Composite
+// =================================================================================================
+
+// Events
+
+#[revisioned(revision = 1)]
+#[derive(new, Event, Debug)]
+#[event(identifier(course_defined), tags(course(&this.course)))]
+pub struct CourseDefined {
+    #[new(into)]
+    pub course: String,
+    pub capacity: u64,
+}
+
+#[revisioned(revision = 1)]
+#[derive(new, Event, Debug)]
+#[event(
+    identifier(student_subscribed),
+    tags(course(&this.course), student(&this.student))
+)]
+pub struct StudentSubscribed {
+    #[new(into)]
+    pub course: String,
+    #[new(into)]
+    pub student: String,
+}
+
+// Projections
+
+#[derive(new, Projection, Debug)]
+#[projection(
+    select(
+        events(CourseDefined),
+        filter(course(&this.course))
+    )
+)]
+pub struct CourseExists {
+    #[new(into)]
+    pub course: String,
+    #[new(default)]
+    #[projection(exists(CourseDefined))]
+    pub exists: bool,
+}
+
+#[derive(new, Projection, Debug)]
+#[projection(
+    select(
+        events(StudentSubscribed),
+        filter(student(&this.student))
+    )
+)]
+pub struct StudentSubscriptions {
+    #[new(into)]
+    pub student: String,
+    #[new(default)]
+    #[projection(count(StudentSubscribed))]
+    pub count: u64,
+}
+
+// Actions
+
+#[derive(new, Action, Clone, Debug)]
+#[action(
+    projection(CourseExists: CourseExists::new(&this.course)),
+    forbid(|_, context| context.course_exists.exists, DefineError::AlreadyDefined)
+)]
+pub struct DefineCourse {
+    #[new(into)]
+    pub course: String,
+    pub capacity: u64,
+}
+
+impl Act for DefineCourse {
+    type Err = DefineError;
+    type Ok = u64;
+
+    fn action(&mut self, context: &mut Self::Context) -> Result<Self::Ok, Self::Err> {
+        context.append(&CourseDefined::new(&self.course, self.capacity))?;
+
+        Ok(self.capacity)
+    }
+}
+
+#[derive(new, Action, Clone, Debug)]
+#[action(
+    projection(CourseExists: CourseExists::new(&this.course)),
+    projection(StudentSubscriptions: StudentSubscriptions::new(&this.student)),
+    require(|_, context| context.course_exists.exists, SubscribeError::NoCourse),
+    forbid(
+        |_, context| context.student_subscriptions.count >= 2,
+        SubscribeError::TooMany
+    )
+)]
+pub struct Subscribe {
+    #[new(into)]
+    pub course: String,
+    #[new(into)]
+    pub student: String,
+}
+
+impl Act for Subscribe {
+    type Err = SubscribeError;
+    type Ok = u64;
+
+    fn action(&mut self, context: &mut Self::Context) -> Result<Self::Ok, Self::Err> {
+        let count = context.student_subscriptions.count + 1;
+
+        context.append(&StudentSubscribed::new(&self.course, &self.student))?;
+
+        Ok(count)
+    }
+}
+
+// Errors
+
+#[derive(Debug, Error)]
+pub enum DefineError {
+    #[error("Already Defined")]
+    AlreadyDefined,
+    #[error(transparent)]
+    Model(#[from] eventric_model::error::Error),
+    #[error(transparent)]
+    Stream(#[from] eventric_stream::error::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum SubscribeError {
+    #[error("No Course")]
+    NoCourse,
+    #[error("Too Many")]
+    TooMany,
+    #[error(transparent)]
+    Model(#[from] eventric_model::error::Error),
+    #[error(transparent)]
+    Stream(#[from] eventric_stream::error::Error),
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Tests
+
+#[test]
+fn composites_enact_both_actions_atomically() {
+    let (_dir, mut stream) = stream();
+
+    assert_eq!(
+        stream.enact(define_and_subscribe("cs101", "ann")).unwrap(),
+        (30, 1)
+    );
+    assert!(matches!(
+        stream.enact(Subscribe::new("cs101", "bob")),
+        Ok(1)
+    ));
+}
+
+#[test]
+fn second_actions_see_events_of_first_actions() {
+    let (_dir, mut stream) = stream();
+
+    assert!(matches!(
+        stream.enact(Subscribe::new("cs101", "ann")),
+        Err(SubscribeError::NoCourse)
+    ));
+    assert!(stream.enact(define_and_subscribe("cs101", "ann")).is_ok());
+}
+
+#[test]
+fn errors_identify_the_failing_action() {
+    let (_dir, mut stream) = stream();
+
+    stream.enact(define_and_subscribe("cs101", "ann")).unwrap();
+
+    assert!(matches!(
+        stream.enact(define_and_subscribe("cs101", "bob")),
+        Err(CompositeError::First(DefineError::AlreadyDefined))
+    ));
+
+    stream.enact(define_and_subscribe("cs102", "ann")).unwrap();
+
+    assert!(matches!(
+        stream.enact(define_and_subscribe("cs103", "ann")),
+        Err(CompositeError::Second(SubscribeError::TooMany))
+    ));
+    assert!(stream.enact(define_and_subscribe("cs103", "bob")).is_ok());
+}
+
+#[test]
+fn invariants_cover_both_actions() {
+    let invariants = <Composite<DefineCourse, Subscribe>>::INVARIANTS;
+
+    let errors = invariants
+        .iter()
+        .map(|invariant: &Invariant| invariant.error)
+        .collect::<Vec<_>>();
+
+    assert_eq!(errors.len(), 3);
+    assert!(errors[0].contains("AlreadyDefined"));
+    assert!(errors[1].contains("NoCourse"));
+    assert!(errors[2].contains("TooMany"));
+}
+
+#[test]
+fn composites_are_enacted_in_batches() {
+    let (_dir, mut stream) = stream();
+
+    let results = stream.enact_batch([
+        define_and_subscribe("cs101", "ann"),
+        define_and_subscribe("cs101", "bob"),
+    ]);
+
+    assert!(matches!(results.as_slice(), [
+        Ok((30, 1)),
+        Err(CompositeError::First(DefineError::AlreadyDefined))
+    ]));
+}
+
+#[test]
+fn composites_are_enacted_through_registries_and_buses() {
+    let (_dir, stream) = stream();
+
+    let mut registry = Registry::new(stream).register::<Composite<DefineCourse, Subscribe>>();
+
+    assert_eq!(
+        registry
+            .enact(define_and_subscribe("cs101", "ann"))
+            .unwrap(),
+        (30, 1)
+    );
+
+    let bus = CommandBus::new(registry.stream, 4);
+
+    assert_eq!(
+        bus.enact(define_and_subscribe("cs102", "ann")).unwrap(),
+        (30, 2)
+    );
+}
+
+// Helpers
+
+fn define_and_subscribe(course: &str, student: &str) -> Composite<DefineCourse, Subscribe> {
+    Composite::new(
+        DefineCourse::new(course, 30),
+        Subscribe::new(course, student),
+    )
+}
+
+fn stream() -> (TempDir, Stream) {
+    let dir = TempDir::new().expect("temp dir");
+    let stream = Stream::builder(&dir.path().to_string_lossy())
+        .open()
+        .expect("stream");
+
+    (dir, stream)
+}
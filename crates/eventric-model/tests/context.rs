@@ -0,0 +1,144 @@
+#![feature(associated_type_defaults)]
+#![feature(if_let_guard)]
+#![feature(once_cell_try)]
+
+use eventric_model::{
+    Enactor as _,
+    action::{
+        Act,
+        Action,
+        Context,
+    },
+    event::{
+        Event,
+        Events,
+    },
+    projection::Projection,
+};
+use eventric_stream::stream::Stream;
+use fancy_constructor::new;
+use revision::revisioned;
+use tempfile::TempDir;
+use thiserror::Error;
+
+// =================================================================================================
+// Context
+// =================================================================================================
+
+// Events
+
+#[revisioned(revision = 1)]
+#[derive(new, Event, Debug)]
+#[event(identifier(room_booked), tags(room(&this.room), guest(&this.guest)))]
+pub struct RoomBooked {
+    #[new(into)]
+    pub room: String,
+    #[new(into)]
+    pub guest: String,
+}
+
+// Projections
+
+#[derive(new, Projection, Debug)]
+#[projection(
+    select(
+        events(RoomBooked),
+        filter(room(&this.room))
+    )
+)]
+pub struct RoomBookings {
+    #[new(into)]
+    pub room: String,
+    #[new(default)]
+    #[projection(count(RoomBooked))]
+    pub count: u64,
+}
+
+#[derive(new, Projection, Debug)]
+#[projection(
+    select(
+        events(RoomBooked),
+        filter(guest(&this.guest))
+    )
+)]
+pub struct GuestBookings {
+    #[new(into)]
+    pub guest: String,
+    #[new(default)]
+    #[projection(count(RoomBooked))]
+    pub count: u64,
+}
+
+// Actions
+
+#[derive(new, Action, Debug)]
+#[action(
+    context = BookContext,
+    forbid(|_, context| context.room_bookings.count > 0, BookingError::RoomTaken)
+)]
+pub struct Book {
+    #[new(into)]
+    pub room: String,
+    #[new(into)]
+    pub guest: String,
+}
+
+#[derive(Context, Debug)]
+#[context(action = Book)]
+pub struct BookContext {
+    events: Events,
+    #[projection(init = RoomBookings::new(&action.room))]
+    room_bookings: RoomBookings,
+    #[projection(init = GuestBookings::new(&action.guest))]
+    guest: GuestBookings,
+}
+
+impl Act for Book {
+    type Err = BookingError;
+    type Ok = u64;
+
+    fn action(&mut self, context: &mut Self::Context) -> Result<Self::Ok, Self::Err> {
+        context.append(&RoomBooked::new(&self.room, &self.guest))?;
+
+        Ok(context.guest.count)
+    }
+}
+
+// Errors
+
+#[derive(Debug, Error)]
+pub enum BookingError {
+    #[error("Room Taken")]
+    RoomTaken,
+    #[error(transparent)]
+    Model(#[from] eventric_model::error::Error),
+    #[error(transparent)]
+    Stream(#[from] eventric_stream::error::Error),
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Tests
+
+#[test]
+fn hand_written_contexts_are_initialized_and_updated() {
+    let (_dir, mut stream) = stream();
+
+    assert_eq!(stream.enact(Book::new("101", "ann")).unwrap(), 0);
+    assert_eq!(stream.enact(Book::new("102", "ann")).unwrap(), 1);
+    assert!(matches!(
+        stream.enact(Book::new("101", "bob")),
+        Err(BookingError::RoomTaken)
+    ));
+}
+
+// Helpers
+
+fn stream() -> (TempDir, Stream) {
+    let dir = TempDir::new().expect("temp dir");
+    let stream = Stream::builder(&dir.path().to_string_lossy())
+        .open()
+        .expect("stream");
+
+    (dir, stream)
+}
@@ -0,0 +1,212 @@
+#![feature(associated_type_defaults)]
+#![feature(if_let_guard)]
+#![feature(once_cell_try)]
+
+use std::sync::Mutex;
+
+use eventric_model::{
+    Enactor as _,
+    action::{
+        Act,
+        Action,
+    },
+    event::Event,
+    projection::{
+        Projection,
+        Undecoded,
+    },
+};
+use eventric_stream::{
+    event::Position,
+    stream::Stream,
+};
+use fancy_constructor::new;
+use revision::revisioned;
+use tempfile::TempDir;
+use thiserror::Error;
+
+// =================================================================================================
+// Decode
+// =================================================================================================
+
+// Events
+
+#[revisioned(revision = 1)]
+#[derive(new, Event, Debug)]
+#[event(identifier(seat_booked), tags(show(&this.show)))]
+pub struct SeatBooked {
+    #[new(into)]
+    pub show: String,
+    pub seat: u64,
+}
+
+#[revisioned(revision = 1)]
+#[derive(new, Event, Debug)]
+#[event(identifier(seat_booked), tags(show(&this.show)))]
+pub struct MalformedSeatBooked {
+    #[new(into)]
+    pub show: String,
+}
+
+// Projections
+
+#[derive(new, Projection, Debug)]
+#[projection(
+    decode(skip),
+    select(
+        events(SeatBooked),
+        filter(show(&this.show))
+    )
+)]
+pub struct Skipping {
+    #[new(into)]
+    pub show: String,
+    #[new(default)]
+    #[projection(count(SeatBooked))]
+    pub booked: u64,
+}
+
+#[derive(new, Projection, Debug)]
+#[projection(
+    decode(dead_letter(dead_letter)),
+    select(
+        events(SeatBooked),
+        filter(show(&this.show))
+    )
+)]
+pub struct DeadLettering {
+    #[new(into)]
+    pub show: String,
+    #[new(default)]
+    #[projection(count(SeatBooked))]
+    pub booked: u64,
+}
+
+#[derive(new, Projection, Debug)]
+#[projection(
+    select(
+        events(SeatBooked),
+        filter(show(&this.show))
+    )
+)]
+pub struct Failing {
+    #[new(into)]
+    pub show: String,
+    #[new(default)]
+    #[projection(count(SeatBooked))]
+    pub booked: u64,
+}
+
+static DEAD_LETTERS: Mutex<Vec<(String, Option<Position>)>> = Mutex::new(Vec::new());
+
+fn dead_letter(event: Undecoded<'_>, _: &eventric_stream::error::Error) {
+    let show = format!("{:?}", event.tags());
+
+    DEAD_LETTERS.lock().unwrap().push((show, event.position()));
+}
+
+// Actions
+
+#[derive(new, Action, Clone, Debug)]
+#[action(
+    projection(Skipping: Skipping::new(&this.show)),
+    projection(DeadLettering: DeadLettering::new(&this.show))
+)]
+pub struct Book {
+    #[new(into)]
+    pub show: String,
+    pub malformed: bool,
+}
+
+impl Act for Book {
+    type Err = DecodeError;
+    type Ok = (u64, u64);
+
+    fn action(&mut self, context: &mut Self::Context) -> Result<Self::Ok, Self::Err> {
+        let booked = (context.skipping.booked, context.dead_lettering.booked);
+
+        if self.malformed {
+            context.append(&MalformedSeatBooked::new(&self.show))?;
+        } else {
+            context.append(&SeatBooked::new(&self.show, booked.0))?;
+        }
+
+        Ok(booked)
+    }
+}
+
+#[derive(new, Action, Debug)]
+#[action(projection(Failing: Failing::new(&this.show)))]
+pub struct Inspect {
+    #[new(into)]
+    pub show: String,
+}
+
+impl Act for Inspect {
+    type Err = DecodeError;
+    type Ok = u64;
+
+    fn action(&mut self, context: &mut Self::Context) -> Result<Self::Ok, Self::Err> {
+        Ok(context.failing.booked)
+    }
+}
+
+// Errors
+
+#[derive(Debug, Error)]
+pub enum DecodeError {
+    #[error(transparent)]
+    Model(#[from] eventric_model::error::Error),
+    #[error(transparent)]
+    Stream(#[from] eventric_stream::error::Error),
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Tests
+
+#[test]
+fn undecodable_stored_events_are_recovered_by_policy() {
+    let (_dir, mut stream) = stream();
+
+    stream.enact(Book::new("matinee", false)).unwrap();
+    stream.enact(Book::new("matinee", true)).unwrap();
+
+    assert_eq!(stream.enact(Book::new("matinee", false)).unwrap(), (1, 1));
+    assert!(matches!(
+        stream.enact(Inspect::new("matinee")),
+        Err(DecodeError::Model(_) | DecodeError::Stream(_))
+    ));
+    assert!(dead_letters("matinee").contains(&Some(Position::new(1))));
+}
+
+#[test]
+fn undecodable_candidate_events_are_recovered_by_policy() {
+    let (_dir, mut stream) = stream();
+
+    let results = stream.enact_batch([Book::new("evening", true), Book::new("evening", false)]);
+
+    assert!(matches!(results.as_slice(), [Ok((0, 0)), Ok((0, 0))]));
+    assert!(dead_letters("evening").contains(&None));
+}
+
+// Helpers
+
+fn dead_letters(show: &str) -> Vec<Option<Position>> {
+    DEAD_LETTERS
+        .lock()
+        .unwrap()
+        .iter()
+        .filter(|(tags, _)| tags.contains(show))
+        .map(|(_, position)| *position)
+        .collect()
+}
+
+fn stream() -> (TempDir, Stream) {
+    let dir = TempDir::new().expect("temp dir");
+    let stream = Stream::builder(&dir.path().to_string_lossy())
+        .open()
+        .expect("stream");
+
+    (dir, stream)
+}
@@ -0,0 +1,35 @@
+use std::error::Error as _;
+
+use eventric_model::error::{
+    BusError,
+    Error,
+    InitializeError,
+    OutboxError,
+};
+
+// =================================================================================================
+// Error
+// =================================================================================================
+
+// Tests
+
+#[test]
+fn sources_are_chained_rather_than_displayed() {
+    let error = InitializeError::of::<u8, _>("missing");
+
+    assert_eq!(error.to_string(), "initializer for projection u8 failed");
+    assert_eq!(error.source().unwrap().to_string(), "missing");
+
+    let error = OutboxError::Sink("unreachable".into());
+
+    assert_eq!(error.to_string(), "outbox sink failed");
+    assert_eq!(error.source().unwrap().to_string(), "unreachable");
+}
+
+#[test]
+fn module_errors_convert_into_the_model_error() {
+    let error = Error::from(BusError::Full);
+
+    assert!(matches!(error, Error::Bus(BusError::Full)));
+    assert_eq!(error.to_string(), "command bus full");
+}
@@ -0,0 +1,367 @@
+#![feature(associated_type_defaults)]
+#![feature(if_let_guard)]
+#![feature(once_cell_try)]
+
+use std::{
+    borrow::Cow,
+    io::{
+        Read,
+        Write,
+    },
+    marker::PhantomData,
+};
+
+use eventric_model::{
+    Enactor as _,
+    action::{
+        Act,
+        Action,
+    },
+    event::{
+        Event,
+        Identifier,
+        Identifiers,
+        Identify as _,
+        Specifier,
+        Specifiers,
+        Tags as _,
+        Variants,
+    },
+    projection::Projection,
+};
+use eventric_stream::{
+    event::tag,
+    stream::Stream,
+};
+use fancy_constructor::new;
+use revision::{
+    DeserializeRevisioned,
+    Revisioned,
+    SerializeRevisioned,
+    revisioned,
+};
+use tempfile::TempDir;
+use thiserror::Error;
+
+// =================================================================================================
+// Event
+// =================================================================================================
+
+// Events
+
+#[revisioned(revision = 1)]
+#[derive(Event, Debug)]
+pub enum Loan {
+    #[event(
+        identifier(book_borrowed),
+        tags(book(book), member(|this| this.member()))
+    )]
+    Borrowed { book: String, member: String },
+    #[event(identifier(book_returned), tags(book(book)))]
+    Returned { book: String },
+}
+
+impl Loan {
+    fn member(&self) -> Cow<'_, str> {
+        match self {
+            Self::Borrowed { member, .. } => Cow::Borrowed(member),
+            Self::Returned { .. } => Cow::Borrowed("library"),
+        }
+    }
+}
+
+#[revisioned(revision = 1)]
+#[derive(new, Event, Debug)]
+#[event(identifier(book_catalogued), tags(book(&this.book)))]
+pub struct BookCatalogued {
+    #[new(into)]
+    pub book: String,
+}
+
+#[derive(Event, Debug)]
+#[event(identifier = format!("distance_walked_in_{}", U::NAME), tags(walker(&this.walker)))]
+pub struct DistanceWalked<U>
+where
+    U: Unit,
+{
+    pub walker: String,
+    pub distance: u64,
+    unit: PhantomData<U>,
+}
+
+impl<U> DistanceWalked<U>
+where
+    U: Unit,
+{
+    pub fn new(walker: impl Into<String>, distance: u64) -> Self {
+        let walker = walker.into();
+        let unit = PhantomData;
+
+        Self {
+            walker,
+            distance,
+            unit,
+        }
+    }
+}
+
+impl<U> Revisioned for DistanceWalked<U>
+where
+    U: Unit,
+{
+    fn revision() -> u16 {
+        1
+    }
+}
+
+impl<U> SerializeRevisioned for DistanceWalked<U>
+where
+    U: Unit,
+{
+    fn serialize_revisioned<W: Write>(&self, writer: &mut W) -> Result<(), revision::Error> {
+        self.walker.serialize_revisioned(writer)?;
+        self.distance.serialize_revisioned(writer)
+    }
+}
+
+impl<U> DeserializeRevisioned for DistanceWalked<U>
+where
+    U: Unit,
+{
+    fn deserialize_revisioned<R: Read>(reader: &mut R) -> Result<Self, revision::Error> {
+        let walker = String::deserialize_revisioned(reader)?;
+        let distance = u64::deserialize_revisioned(reader)?;
+
+        Ok(Self::new(walker, distance))
+    }
+}
+
+pub trait Unit: 'static {
+    const NAME: &'static str;
+}
+
+#[derive(Debug)]
+pub struct Feet;
+
+impl Unit for Feet {
+    const NAME: &'static str = "feet";
+}
+
+#[derive(Debug)]
+pub struct Metres;
+
+impl Unit for Metres {
+    const NAME: &'static str = "metres";
+}
+
+// Projections
+
+#[derive(new, Projection, Debug)]
+#[projection(
+    select(
+        variants(Loan::Borrowed),
+        filter(book(&this.book))
+    ),
+    on(Loan => |s, _| s.borrowed += 1)
+)]
+pub struct Borrowings {
+    #[new(into)]
+    pub book: String,
+    #[new(default)]
+    pub borrowed: u64,
+}
+
+#[derive(new, Projection, Debug)]
+#[projection(
+    select(
+        events(Loan),
+        filter(book(&this.book))
+    ),
+    on(Loan => |s, e| s.loans.push(e.identifier().clone()))
+)]
+pub struct Loans {
+    #[new(into)]
+    pub book: String,
+    #[new(default)]
+    pub loans: Vec<eventric_stream::event::Identifier>,
+}
+
+// Actions
+
+#[derive(new, Projection, Debug)]
+#[projection(
+    select(
+        events(DistanceWalked<Metres>),
+        filter(walker(&this.walker))
+    ),
+    on(DistanceWalked<Metres> => |s, e| s.metres += e.distance)
+)]
+pub struct Walked {
+    #[new(into)]
+    pub walker: String,
+    #[new(default)]
+    pub metres: u64,
+}
+
+#[derive(new, Action, Debug)]
+#[action(
+    projection(Borrowings: Borrowings::new(&this.book)),
+    projection(Loans: Loans::new(&this.book))
+)]
+pub struct Lend {
+    #[new(into)]
+    pub book: String,
+    #[new(into)]
+    pub member: String,
+    pub returned: bool,
+}
+
+impl Act for Lend {
+    type Err = EventError;
+    type Ok = (u64, usize);
+
+    fn action(&mut self, context: &mut Self::Context) -> Result<Self::Ok, Self::Err> {
+        let seen = (context.borrowings.borrowed, context.loans.loans.len());
+
+        let book = self.book.clone();
+        let member = self.member.clone();
+
+        if self.returned {
+            context.append(&Loan::Returned { book })?;
+        } else {
+            context.append(&Loan::Borrowed { book, member })?;
+        }
+
+        Ok(seen)
+    }
+}
+
+#[derive(new, Action, Debug)]
+#[action(projection(Walked: Walked::new(&this.walker)))]
+pub struct Walk {
+    #[new(into)]
+    pub walker: String,
+    pub metres: u64,
+    pub feet: u64,
+}
+
+impl Act for Walk {
+    type Err = EventError;
+    type Ok = u64;
+
+    fn action(&mut self, context: &mut Self::Context) -> Result<Self::Ok, Self::Err> {
+        context.append(&DistanceWalked::<Metres>::new(&self.walker, self.metres))?;
+        context.append(&DistanceWalked::<Feet>::new(&self.walker, self.feet))?;
+
+        Ok(context.walked.metres)
+    }
+}
+
+// Errors
+
+#[derive(Debug, Error)]
+pub enum EventError {
+    #[error(transparent)]
+    Model(#[from] eventric_model::error::Error),
+    #[error(transparent)]
+    Stream(#[from] eventric_stream::error::Error),
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Tests
+
+#[test]
+fn enum_variants_have_their_own_identifiers() {
+    let borrowed = Loan::variant("Borrowed").unwrap();
+    let returned = Loan::variant("Returned").unwrap();
+
+    assert_ne!(borrowed, returned);
+    assert_eq!(Loan::identifiers().unwrap(), [borrowed, returned]);
+    assert_eq!(Loan::specifiers().unwrap().len(), 2);
+
+    let event = Loan::Returned {
+        book: String::from("dune"),
+    };
+
+    assert_eq!(event.identify().unwrap(), returned);
+}
+
+#[test]
+fn enum_variant_tags_bind_fields_and_the_closure_parameter() {
+    let borrowed = Loan::Borrowed {
+        book: String::from("dune"),
+        member: String::from("ann"),
+    };
+    let returned = Loan::Returned {
+        book: String::from("dune"),
+    };
+
+    let mut tags = borrowed.tags().unwrap();
+
+    tags.sort();
+
+    assert_eq!(tags, [
+        tag!(book, "dune").unwrap(),
+        tag!(member, "ann").unwrap()
+    ]);
+    assert_eq!(returned.tags().unwrap(), [tag!(book, "dune").unwrap()]);
+}
+
+#[test]
+fn struct_events_still_provide_a_specifier() {
+    assert!(BookCatalogued::specifier().is_ok());
+}
+
+#[test]
+fn projections_select_families_or_single_variants() {
+    let (_dir, mut stream) = stream();
+
+    stream.enact(Lend::new("dune", "ann", false)).unwrap();
+    stream.enact(Lend::new("dune", "ann", true)).unwrap();
+    stream.enact(Lend::new("emma", "bob", false)).unwrap();
+
+    assert_eq!(
+        stream.enact(Lend::new("dune", "bob", false)).unwrap(),
+        (1, 2)
+    );
+}
+
+#[test]
+fn generic_events_have_an_identifier_per_instantiation() {
+    let metres = DistanceWalked::<Metres>::identifier().unwrap();
+    let feet = DistanceWalked::<Feet>::identifier().unwrap();
+
+    assert_eq!(
+        metres,
+        &eventric_stream::event::Identifier::new("distance_walked_in_metres").unwrap()
+    );
+    assert_eq!(
+        feet,
+        &eventric_stream::event::Identifier::new("distance_walked_in_feet").unwrap()
+    );
+    assert!(std::ptr::eq(
+        metres,
+        DistanceWalked::<Metres>::identifier().unwrap()
+    ));
+}
+
+#[test]
+fn projections_select_single_instantiations() {
+    let (_dir, mut stream) = stream();
+
+    stream.enact(Walk::new("ann", 5, 10)).unwrap();
+
+    assert_eq!(stream.enact(Walk::new("ann", 7, 10)).unwrap(), 5);
+}
+
+// Helpers
+
+fn stream() -> (TempDir, Stream) {
+    let dir = TempDir::new().expect("temp dir");
+    let stream = Stream::builder(&dir.path().to_string_lossy())
+        .open()
+        .expect("stream");
+
+    (dir, stream)
+}
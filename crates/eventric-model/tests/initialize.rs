@@ -0,0 +1,195 @@
+#![feature(associated_type_defaults)]
+#![feature(if_let_guard)]
+#![feature(once_cell_try)]
+
+use std::cell::Cell;
+
+use eventric_model::{
+    Enactor as _,
+    action::{
+        Act,
+        Action,
+        Context,
+    },
+    error::{
+        Error,
+        InitializeError,
+    },
+    event::{
+        Event,
+        Events,
+    },
+    projection::Projection,
+};
+use eventric_stream::stream::Stream;
+use fancy_constructor::new;
+use revision::revisioned;
+use tempfile::TempDir;
+use thiserror::Error;
+
+// =================================================================================================
+// Initialize
+// =================================================================================================
+
+// Events
+
+#[revisioned(revision = 1)]
+#[derive(new, Event, Debug)]
+#[event(identifier(parcel_shipped), tags(parcel(&this.parcel)))]
+pub struct ParcelShipped {
+    #[new(into)]
+    pub parcel: String,
+}
+
+// Projections
+
+#[derive(new, Projection, Debug)]
+#[projection(
+    select(
+        events(ParcelShipped),
+        filter(parcel(&this.parcel))
+    )
+)]
+pub struct Shipments {
+    #[new(into)]
+    pub parcel: String,
+    #[new(default)]
+    #[projection(count(ParcelShipped))]
+    pub count: u64,
+}
+
+thread_local! {
+    static INITIALIZED: Cell<usize> = const { Cell::new(0) };
+}
+
+fn normalize(parcel: &str) -> Result<Shipments, ParcelError> {
+    INITIALIZED.set(INITIALIZED.get() + 1);
+
+    match parcel.trim() {
+        "" => Err(ParcelError::Blank),
+        parcel => Ok(Shipments::new(parcel.to_uppercase())),
+    }
+}
+
+// Actions
+
+#[derive(new, Action, Debug)]
+#[action(projection(Shipments: ?|this| normalize(&this.parcel)))]
+pub struct Ship {
+    #[new(into)]
+    pub parcel: String,
+}
+
+impl Act for Ship {
+    type Err = ParcelError;
+    type Ok = u64;
+
+    fn action(&mut self, context: &mut Self::Context) -> Result<Self::Ok, Self::Err> {
+        let count = context.shipments.count;
+        let parcel = context.shipments.parcel.clone();
+
+        context.append(&ParcelShipped::new(parcel))?;
+
+        Ok(count)
+    }
+
+    fn validate(&self) -> Result<(), Self::Err> {
+        match self.parcel.len() {
+            0..=16 => Ok(()),
+            _ => Err(ParcelError::TooLong),
+        }
+    }
+}
+
+#[derive(new, Action, Debug)]
+#[action(context = ResendContext)]
+pub struct Resend {
+    #[new(into)]
+    pub parcel: String,
+}
+
+#[derive(Context, Debug)]
+#[context(action = Resend)]
+pub struct ResendContext {
+    events: Events,
+    #[projection(try_init = normalize(&action.parcel))]
+    shipments: Shipments,
+}
+
+impl Act for Resend {
+    type Err = ParcelError;
+    type Ok = u64;
+
+    fn action(&mut self, context: &mut Self::Context) -> Result<Self::Ok, Self::Err> {
+        Ok(context.shipments.count)
+    }
+}
+
+// Errors
+
+#[derive(Debug, Error)]
+pub enum ParcelError {
+    #[error("Blank Parcel")]
+    Blank,
+    #[error("Parcel Too Long")]
+    TooLong,
+    #[error(transparent)]
+    Model(#[from] eventric_model::error::Error),
+    #[error(transparent)]
+    Stream(#[from] eventric_stream::error::Error),
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Tests
+
+#[test]
+fn fallible_initializers_compute_projections() {
+    let (_dir, mut stream) = stream();
+
+    assert_eq!(stream.enact(Ship::new("ab-1")).unwrap(), 0);
+    assert_eq!(stream.enact(Ship::new(" AB-1 ")).unwrap(), 1);
+    assert_eq!(stream.enact(Resend::new("ab-1")).unwrap(), 2);
+}
+
+#[test]
+fn failing_initializers_abort_the_enactment() {
+    let (_dir, mut stream) = stream();
+
+    let error = stream.enact(Ship::new(" ")).unwrap_err();
+
+    assert!(matches!(
+        &error,
+        ParcelError::Model(Error::Initialize(InitializeError { projection, .. }))
+            if projection.ends_with("Shipments")
+    ));
+    assert!(matches!(
+        stream.enact(Resend::new("")),
+        Err(ParcelError::Model(Error::Initialize(_)))
+    ));
+    assert_eq!(stream.enact(Ship::new("ab-1")).unwrap(), 0);
+}
+
+#[test]
+fn invalid_actions_are_rejected_before_initialization() {
+    let (_dir, mut stream) = stream();
+
+    let initialized = INITIALIZED.get();
+
+    assert!(matches!(
+        stream.enact(Ship::new("a-very-long-parcel-id")),
+        Err(ParcelError::TooLong)
+    ));
+    assert_eq!(INITIALIZED.get(), initialized);
+}
+
+// Helpers
+
+fn stream() -> (TempDir, Stream) {
+    let dir = TempDir::new().expect("temp dir");
+    let stream = Stream::builder(&dir.path().to_string_lossy())
+        .open()
+        .expect("stream");
+
+    (dir, stream)
+}
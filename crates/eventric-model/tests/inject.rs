@@ -0,0 +1,220 @@
+#![feature(associated_type_defaults)]
+#![feature(if_let_guard)]
+#![feature(once_cell_try)]
+
+use eventric_model::{
+    Enactor as _,
+    action::{
+        Act,
+        Action,
+        Context,
+    },
+    event::{
+        Event,
+        Events,
+    },
+    projection::Projection,
+};
+use eventric_stream::stream::Stream;
+use fancy_constructor::new;
+use revision::revisioned;
+use tempfile::TempDir;
+use thiserror::Error;
+
+// =================================================================================================
+// Inject
+// =================================================================================================
+
+// Events
+
+#[revisioned(revision = 1)]
+#[derive(new, Event, Debug)]
+#[event(identifier(invoice_issued), tags(invoice(&this.invoice)))]
+pub struct InvoiceIssued {
+    #[new(into)]
+    pub invoice: String,
+    pub issued_at: u64,
+    pub total: u64,
+}
+
+// Projections
+
+#[derive(new, Projection, Debug)]
+#[projection(
+    select(
+        events(InvoiceIssued),
+        filter(invoice(&this.invoice))
+    )
+)]
+pub struct Issued {
+    #[new(into)]
+    pub invoice: String,
+    #[new(default)]
+    #[projection(exists(InvoiceIssued))]
+    pub exists: bool,
+}
+
+// Actions
+
+#[derive(Debug)]
+pub struct Clock(pub u64);
+
+#[derive(Debug)]
+pub struct Pricing {
+    pub rate: u64,
+}
+
+#[derive(new, Action, Debug)]
+#[action(
+    projection(Issued: Issued::new(&this.invoice)),
+    inject(clock: Clock, pricing: Pricing),
+    forbid(|_, context| context.issued.exists, InjectError::Issued)
+)]
+pub struct Issue {
+    #[new(into)]
+    pub invoice: String,
+    pub hours: u64,
+}
+
+impl Act for Issue {
+    type Err = InjectError;
+    type Ok = (u64, u64);
+
+    fn action(&mut self, context: &mut Self::Context) -> Result<Self::Ok, Self::Err> {
+        let issued_at = context.clock.0;
+        let total = self.hours * context.pricing.rate;
+
+        context.append(&InvoiceIssued::new(&self.invoice, issued_at, total))?;
+
+        Ok((issued_at, total))
+    }
+}
+
+#[derive(new, Action, Debug)]
+#[action(context = ReissueContext)]
+pub struct Reissue {
+    #[new(into)]
+    pub invoice: String,
+}
+
+#[derive(Context, Debug)]
+#[context(action = Reissue)]
+pub struct ReissueContext {
+    events: Events,
+    #[projection(init = Issued::new(&action.invoice))]
+    issued: Issued,
+    #[context(inject)]
+    clock: Clock,
+}
+
+impl Act for Reissue {
+    type Err = InjectError;
+    type Ok = u64;
+
+    fn action(&mut self, context: &mut Self::Context) -> Result<Self::Ok, Self::Err> {
+        if !context.issued.exists {
+            return Err(InjectError::Missing);
+        }
+
+        let issued_at = context.clock.0;
+
+        context.append(&InvoiceIssued::new(&self.invoice, issued_at, 0))?;
+
+        Ok(issued_at)
+    }
+}
+
+// Errors
+
+#[derive(Debug, Error)]
+pub enum InjectError {
+    #[error("Invoice Issued")]
+    Issued,
+    #[error("Invoice Missing")]
+    Missing,
+    #[error(transparent)]
+    Model(#[from] eventric_model::error::Error),
+    #[error(transparent)]
+    Stream(#[from] eventric_stream::error::Error),
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Tests
+
+#[test]
+fn injected_dependencies_are_supplied_at_enact_time() {
+    let (_dir, mut stream) = stream();
+
+    let deps = IssueDeps {
+        clock: Clock(100),
+        pricing: Pricing { rate: 40 },
+    };
+
+    assert_eq!(
+        stream.enact_with(Issue::new("inv-1", 3), deps).unwrap(),
+        (100, 120)
+    );
+
+    let deps = IssueDeps {
+        clock: Clock(200),
+        pricing: Pricing { rate: 50 },
+    };
+
+    assert_eq!(
+        stream.enact_with(Issue::new("inv-2", 3), deps).unwrap(),
+        (200, 150)
+    );
+}
+
+#[test]
+fn injected_dependencies_sit_alongside_projections() {
+    let (_dir, mut stream) = stream();
+
+    let deps = || IssueDeps {
+        clock: Clock(100),
+        pricing: Pricing { rate: 40 },
+    };
+
+    stream.enact_with(Issue::new("inv-1", 3), deps()).unwrap();
+
+    assert!(matches!(
+        stream.enact_with(Issue::new("inv-1", 3), deps()),
+        Err(InjectError::Issued)
+    ));
+}
+
+#[test]
+fn hand_written_contexts_inject_dependencies() {
+    let (_dir, mut stream) = stream();
+
+    assert!(matches!(
+        stream.enact_with(Reissue::new("inv-1"), ReissueDeps { clock: Clock(100) }),
+        Err(InjectError::Missing)
+    ));
+
+    stream
+        .enact_with(Issue::new("inv-1", 3), IssueDeps {
+            clock: Clock(100),
+            pricing: Pricing { rate: 40 },
+        })
+        .unwrap();
+
+    assert_eq!(
+        stream
+            .enact_with(Reissue::new("inv-1"), ReissueDeps { clock: Clock(300) })
+            .unwrap(),
+        300
+    );
+}
+
+// Helpers
+
+fn stream() -> (TempDir, Stream) {
+    let dir = TempDir::new().expect("temp dir");
+    let stream = Stream::builder(&dir.path().to_string_lossy())
+        .open()
+        .expect("stream");
+
+    (dir, stream)
+}
@@ -0,0 +1,233 @@
+#![feature(associated_type_defaults)]
+#![feature(if_let_guard)]
+#![feature(once_cell_try)]
+
+use eventric_model::{
+    Enactor as _,
+    action::{
+        Act,
+        Action,
+    },
+    event::Event,
+    projection::{
+        ProjectionEvent,
+        library::{
+            Count,
+            DistinctTags,
+            Exists,
+            Keyed,
+            Last,
+            Sum,
+        },
+    },
+};
+use eventric_stream::{
+    event::{
+        Position,
+        tag,
+    },
+    stream::Stream,
+};
+use fancy_constructor::new;
+use revision::revisioned;
+use tempfile::TempDir;
+use thiserror::Error;
+
+// =================================================================================================
+// Library
+// =================================================================================================
+
+// Events
+
+#[revisioned(revision = 1)]
+#[derive(new, Event, Clone, Debug, PartialEq)]
+#[event(
+    identifier(student_enrolled),
+    tags(course(&this.course), student(&this.student))
+)]
+pub struct StudentEnrolled {
+    #[new(into)]
+    pub course: String,
+    #[new(into)]
+    pub student: String,
+    pub credits: u64,
+}
+
+// Actions
+
+#[derive(new, Action, Clone, Debug)]
+#[action(
+    projection(
+        Count<StudentEnrolled> @ enrolled:
+            ?|this| tag!(course, &this.course).map(|tag| Count::new([tag]))
+    ),
+    forbid(|_, context| context.enrolled.count >= 2, LibraryError::Full)
+)]
+pub struct Enrol {
+    #[new(into)]
+    pub course: String,
+    #[new(into)]
+    pub student: String,
+    pub credits: u64,
+}
+
+impl Act for Enrol {
+    type Err = LibraryError;
+
+    fn action(&mut self, context: &mut Self::Context) -> Result<Self::Ok, Self::Err> {
+        context.append(&StudentEnrolled::new(
+            &self.course,
+            &self.student,
+            self.credits,
+        ))?;
+
+        Ok(())
+    }
+}
+
+#[derive(new, Action, Debug)]
+#[action(
+    projection(
+        Exists<StudentEnrolled> @ student_exists:
+            ?|this| tag!(student, &this.student).map(|tag| Exists::new([tag]))
+    ),
+    projection(
+        DistinctTags<StudentEnrolled> @ student_tags:
+            ?|this| tag!(student, &this.student).map(|tag| DistinctTags::new([tag]))
+    ),
+    projection(
+        Last<StudentEnrolled> @ last_enrolment:
+            ?|this| tag!(student, &this.student).map(|tag| Last::new([tag]))
+    ),
+    projection(
+        Sum<StudentEnrolled, u64> @ credits:
+            ?|this| tag!(student, &this.student).map(|tag| Sum::new([tag], |event: &StudentEnrolled| event.credits))
+    ),
+    projection(
+        Keyed<String, Count<StudentEnrolled>> @ per_course: |_| {
+            Keyed::new(|course| Count::new([tag!(course, course).expect("tag")]))
+                .key(|event: &ProjectionEvent<'_, StudentEnrolled>| Some(event.course.clone()))
+        }
+    )
+)]
+pub struct Transcript {
+    #[new(into)]
+    pub student: String,
+}
+
+impl Act for Transcript {
+    type Err = LibraryError;
+    type Ok = Summary;
+
+    fn action(&mut self, context: &mut Self::Context) -> Result<Self::Ok, Self::Err> {
+        let per_course = context
+            .per_course
+            .states
+            .iter()
+            .map(|(course, count)| (course.clone(), count.count))
+            .collect();
+
+        Ok(Summary {
+            exists: context.student_exists.exists,
+            tags: context.student_tags.tags.clone(),
+            last: context.last_enrolment.last.clone(),
+            position: context.last_enrolment.position,
+            credits: context.credits.sum,
+            per_course,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct Summary {
+    pub exists: bool,
+    pub tags: Vec<eventric_stream::event::Tag>,
+    pub last: Option<StudentEnrolled>,
+    pub position: Option<Position>,
+    pub credits: u64,
+    pub per_course: Vec<(String, u64)>,
+}
+
+// Errors
+
+#[derive(Debug, Error)]
+pub enum LibraryError {
+    #[error("Course Full")]
+    Full,
+    #[error(transparent)]
+    Model(#[from] eventric_model::error::Error),
+    #[error(transparent)]
+    Stream(#[from] eventric_stream::error::Error),
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Tests
+
+#[test]
+fn filtered_projections_fold_matching_events() {
+    let (_dir, mut stream) = stream();
+
+    stream.enact(Enrol::new("cs101", "ann", 10)).unwrap();
+    stream.enact(Enrol::new("cs101", "bob", 20)).unwrap();
+    stream.enact(Enrol::new("cs102", "ann", 5)).unwrap();
+
+    let summary = stream.enact(Transcript::new("ann")).unwrap();
+
+    assert!(summary.exists);
+    assert_eq!(summary.tags.len(), 3);
+    assert_eq!(summary.last, Some(StudentEnrolled::new("cs102", "ann", 5)));
+    assert_eq!(summary.position, Some(Position::new(2)));
+    assert_eq!(summary.credits, 15);
+
+    let summary = stream.enact(Transcript::new("cat")).unwrap();
+
+    assert!(!summary.exists);
+    assert_eq!(summary.tags, Vec::new());
+    assert_eq!(summary.last, None);
+    assert_eq!(summary.credits, 0);
+}
+
+#[test]
+fn keyed_projections_keep_a_state_per_key() {
+    let (_dir, mut stream) = stream();
+
+    stream.enact(Enrol::new("cs101", "ann", 10)).unwrap();
+    stream.enact(Enrol::new("cs101", "bob", 20)).unwrap();
+    stream.enact(Enrol::new("cs102", "ann", 5)).unwrap();
+
+    let summary = stream.enact(Transcript::new("ann")).unwrap();
+
+    assert_eq!(summary.per_course, [
+        (String::from("cs101"), 2),
+        (String::from("cs102"), 1)
+    ]);
+}
+
+#[test]
+fn filtered_projections_fold_candidate_events() {
+    let (_dir, mut stream) = stream();
+
+    let results = stream.enact_batch([
+        Enrol::new("cs101", "ann", 10),
+        Enrol::new("cs101", "bob", 10),
+        Enrol::new("cs101", "cat", 10),
+    ]);
+
+    assert!(matches!(results.as_slice(), [
+        Ok(()),
+        Ok(()),
+        Err(LibraryError::Full)
+    ]));
+}
+
+// Helpers
+
+fn stream() -> (TempDir, Stream) {
+    let dir = TempDir::new().expect("temp dir");
+    let stream = Stream::builder(&dir.path().to_string_lossy())
+        .open()
+        .expect("stream");
+
+    (dir, stream)
+}
@@ -0,0 +1,219 @@
+#![feature(associated_type_defaults)]
+#![feature(if_let_guard)]
+
+use std::{
+    cell::Cell,
+    error,
+    rc::Rc,
+    sync::mpsc,
+};
+
+use eventric_model::{
+    Enactor as _,
+    action::{
+        Act,
+        Action,
+    },
+    error::OutboxError,
+    outbox::{
+        ChannelSink,
+        OutboxMessage,
+        Relay,
+        Sink,
+    },
+    process::{
+        Checkpoint,
+        MemoryCheckpoint,
+    },
+};
+use eventric_stream::{
+    event::Position,
+    stream::Stream,
+};
+use fancy_constructor::new;
+use tempfile::TempDir;
+use thiserror::Error;
+
+// =================================================================================================
+// Outbox
+// =================================================================================================
+
+// Actions
+
+#[derive(new, Action, Debug)]
+pub struct Publish {
+    #[new(into)]
+    pub topic: String,
+    #[new(into)]
+    pub payload: String,
+}
+
+impl Act for Publish {
+    type Err = OutboxTestError;
+
+    fn action(&mut self, context: &mut Self::Context) -> Result<Self::Ok, Self::Err> {
+        context.append(&OutboxMessage::new(&self.topic, &self.payload))?;
+
+        Ok(())
+    }
+}
+
+// Sinks
+
+#[derive(new, Debug)]
+pub struct FlakySink {
+    #[new(into)]
+    failing: String,
+    #[new(default)]
+    sent: Vec<String>,
+}
+
+impl Sink for FlakySink {
+    fn send(
+        &mut self,
+        message: &OutboxMessage,
+        _: Position,
+    ) -> Result<(), Box<dyn error::Error + Send + Sync>> {
+        if message.payload == self.failing {
+            return Err("unavailable".into());
+        }
+
+        self.sent.push(message.payload.clone());
+
+        Ok(())
+    }
+}
+
+// Checkpoints
+
+#[derive(new, Debug)]
+pub struct CountingCheckpoint {
+    saves: Rc<Cell<usize>>,
+    #[new(default)]
+    position: Option<Position>,
+}
+
+impl Checkpoint for CountingCheckpoint {
+    fn load(&mut self) -> Result<Option<Position>, eventric_model::error::Error> {
+        Ok(self.position)
+    }
+
+    fn save(&mut self, position: Position) -> Result<(), eventric_model::error::Error> {
+        self.saves.set(self.saves.get() + 1);
+        self.position = Some(position);
+
+        Ok(())
+    }
+}
+
+// Errors
+
+#[derive(Debug, Error)]
+pub enum OutboxTestError {
+    #[error(transparent)]
+    Model(#[from] eventric_model::error::Error),
+    #[error(transparent)]
+    Stream(#[from] eventric_stream::error::Error),
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Tests
+
+#[test]
+fn messages_are_delivered_once() {
+    let (_dir, mut stream) = stream();
+
+    let (sender, receiver) = mpsc::channel();
+    let mut relay = Relay::new(ChannelSink::new(sender), MemoryCheckpoint::new());
+
+    stream.enact(Publish::new("courses", "cs:101")).unwrap();
+    stream.enact(Publish::new("courses", "cs:102")).unwrap();
+
+    assert_eq!(relay.run(&stream).unwrap(), 2);
+    assert_eq!(relay.run(&stream).unwrap(), 0);
+
+    stream.enact(Publish::new("courses", "cs:103")).unwrap();
+
+    assert_eq!(relay.run(&stream).unwrap(), 1);
+
+    let payloads = receiver
+        .try_iter()
+        .map(|message| message.payload)
+        .collect::<Vec<_>>();
+
+    assert_eq!(payloads, ["cs:101", "cs:102", "cs:103"]);
+}
+
+#[test]
+fn relays_select_their_topics() {
+    let (_dir, mut stream) = stream();
+
+    let (sender, receiver) = mpsc::channel();
+    let mut relay = Relay::new(ChannelSink::new(sender), MemoryCheckpoint::new())
+        .topic("courses")
+        .topic("students");
+
+    stream.enact(Publish::new("courses", "cs:101")).unwrap();
+    stream.enact(Publish::new("billing", "invoice:1")).unwrap();
+    stream.enact(Publish::new("students", "andrew")).unwrap();
+
+    assert_eq!(relay.run(&stream).unwrap(), 2);
+
+    let topics = receiver
+        .try_iter()
+        .map(|message| message.topic)
+        .collect::<Vec<_>>();
+
+    assert_eq!(topics, ["courses", "students"]);
+}
+
+#[test]
+fn failed_deliveries_keep_earlier_progress() {
+    let (_dir, mut stream) = stream();
+
+    let mut relay = Relay::new(FlakySink::new("cs:102"), MemoryCheckpoint::new());
+
+    stream.enact(Publish::new("courses", "cs:101")).unwrap();
+    stream.enact(Publish::new("courses", "cs:102")).unwrap();
+
+    assert!(matches!(
+        relay.run(&stream),
+        Err(eventric_model::error::Error::Outbox(OutboxError::Sink(_)))
+    ));
+
+    relay.sink.failing.clear();
+
+    assert_eq!(relay.run(&stream).unwrap(), 1);
+    assert_eq!(relay.sink.sent, ["cs:101", "cs:102"]);
+}
+
+#[test]
+fn checkpoints_are_saved_once_per_run() {
+    let (_dir, mut stream) = stream();
+
+    let saves = Rc::new(Cell::new(0));
+    let checkpoint = CountingCheckpoint::new(Rc::clone(&saves));
+    let mut relay = Relay::new(FlakySink::new(""), checkpoint);
+
+    stream.enact(Publish::new("courses", "cs:101")).unwrap();
+    stream.enact(Publish::new("courses", "cs:102")).unwrap();
+    stream.enact(Publish::new("courses", "cs:103")).unwrap();
+
+    assert_eq!(relay.run(&stream).unwrap(), 3);
+    assert_eq!(saves.get(), 1);
+
+    assert_eq!(relay.run(&stream).unwrap(), 0);
+    assert_eq!(saves.get(), 1);
+}
+
+// Helpers
+
+fn stream() -> (TempDir, Stream) {
+    let dir = TempDir::new().expect("temp dir");
+    let stream = Stream::builder(&dir.path().to_string_lossy())
+        .open()
+        .expect("stream");
+
+    (dir, stream)
+}
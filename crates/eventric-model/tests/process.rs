@@ -0,0 +1,226 @@
+#![feature(associated_type_defaults)]
+#![feature(if_let_guard)]
+#![feature(once_cell_try)]
+
+use std::{
+    cell::Cell,
+    mem,
+};
+
+use eventric_model::{
+    Enactor as _,
+    action::{
+        Act,
+        Action,
+    },
+    event::Event,
+    process::{
+        FileCheckpoint,
+        MemoryCheckpoint,
+        Process,
+        ProcessManager,
+    },
+    projection::Projection,
+    reaction::{
+        Reacting,
+        Reaction,
+    },
+};
+use eventric_stream::stream::Stream;
+use fancy_constructor::new;
+use revision::revisioned;
+use tempfile::TempDir;
+use thiserror::Error;
+
+// =================================================================================================
+// Process
+// =================================================================================================
+
+// Events
+
+#[revisioned(revision = 1)]
+#[derive(new, Event, Debug)]
+#[event(identifier(student_subscribed), tags(student(&this.student)))]
+pub struct StudentSubscribed {
+    #[new(into)]
+    pub student: String,
+}
+
+#[revisioned(revision = 1)]
+#[derive(new, Event, Debug)]
+#[event(identifier(welcome_sent), tags(student(&this.student)))]
+pub struct WelcomeSent {
+    #[new(into)]
+    pub student: String,
+}
+
+// Projections
+
+#[derive(new, Projection, Clone, Debug)]
+#[projection(
+    select(events(StudentSubscribed)),
+    on(StudentSubscribed => |s, e| s.pending.push(e.student.clone()))
+)]
+pub struct Welcomer {
+    #[new(default)]
+    pub pending: Vec<String>,
+}
+
+impl ProcessManager for Welcomer {
+    type Action = SendWelcome;
+
+    fn actions(&mut self) -> Vec<Self::Action> {
+        mem::take(&mut self.pending)
+            .into_iter()
+            .map(SendWelcome::new)
+            .collect()
+    }
+}
+
+thread_local! {
+    static OUTAGE: Cell<bool> = const { Cell::new(false) };
+}
+
+// Actions
+
+#[derive(new, Action, Debug)]
+pub struct Subscribe {
+    #[new(into)]
+    pub student: String,
+}
+
+impl Act for Subscribe {
+    type Err = ProcessError;
+
+    fn action(&mut self, context: &mut Self::Context) -> Result<Self::Ok, Self::Err> {
+        context.append(&StudentSubscribed::new(&self.student))?;
+
+        Ok(())
+    }
+}
+
+#[derive(new, Action, Debug)]
+pub struct SendWelcome {
+    #[new(into)]
+    pub student: String,
+}
+
+impl Act for SendWelcome {
+    type Err = ProcessError;
+    type Ok = String;
+
+    fn action(&mut self, context: &mut Self::Context) -> Result<Self::Ok, Self::Err> {
+        if OUTAGE.get() {
+            return Err(ProcessError::Unavailable);
+        }
+
+        context.append(&WelcomeSent::new(&self.student))?;
+
+        Ok(self.student.clone())
+    }
+}
+
+// Errors
+
+#[derive(Debug, Error)]
+pub enum ProcessError {
+    #[error("Mail Unavailable")]
+    Unavailable,
+    #[error(transparent)]
+    Model(#[from] eventric_model::error::Error),
+    #[error(transparent)]
+    Stream(#[from] eventric_stream::error::Error),
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Tests
+
+#[test]
+fn events_trigger_actions_once() {
+    let (_dir, mut stream) = stream();
+
+    let mut process = Process::new(Welcomer::new(), MemoryCheckpoint::new());
+
+    stream.enact(Subscribe::new("ann")).unwrap();
+    stream.enact(Subscribe::new("bob")).unwrap();
+
+    assert_eq!(welcomed(process.run(&mut stream).unwrap()), ["ann", "bob"]);
+    assert_eq!(
+        welcomed(process.run(&mut stream).unwrap()),
+        Vec::<String>::new()
+    );
+
+    stream.enact(Subscribe::new("cat")).unwrap();
+
+    assert_eq!(welcomed(process.run(&mut stream).unwrap()), ["cat"]);
+}
+
+#[test]
+fn failed_actions_are_retried() {
+    let (_dir, mut stream) = stream();
+
+    let mut process = Process::new(Welcomer::new(), MemoryCheckpoint::new());
+
+    stream.enact(Subscribe::new("ann")).unwrap();
+    stream.enact(Subscribe::new("bob")).unwrap();
+
+    OUTAGE.set(true);
+
+    let outcomes = process.run(&mut stream).unwrap();
+
+    assert!(matches!(outcomes.as_slice(), [Err(
+        ProcessError::Unavailable
+    )]));
+
+    OUTAGE.set(false);
+
+    assert_eq!(welcomed(process.run(&mut stream).unwrap()), ["ann", "bob"]);
+}
+
+#[test]
+fn checkpoints_persist_across_processes() {
+    let (dir, mut stream) = stream();
+
+    let path = dir.path().join("welcomer.checkpoint");
+
+    stream.enact(Subscribe::new("ann")).unwrap();
+
+    let mut process = Process::new(Welcomer::new(), FileCheckpoint::new(&path));
+
+    assert_eq!(welcomed(process.run(&mut stream).unwrap()), ["ann"]);
+
+    stream.enact(Subscribe::new("bob")).unwrap();
+
+    let mut process = Process::new(Welcomer::new(), FileCheckpoint::new(&path));
+
+    assert_eq!(welcomed(process.run(&mut stream).unwrap()), ["bob"]);
+}
+
+#[test]
+fn processes_run_on_reacting_streams() {
+    let (_dir, stream) = stream();
+
+    let mut stream = Reacting::new(stream).on(|_: Reaction<WelcomeSent>| {});
+    let mut process = Process::new(Welcomer::new(), MemoryCheckpoint::new());
+
+    stream.enact(Subscribe::new("ann")).unwrap();
+
+    assert_eq!(welcomed(process.run(&mut stream).unwrap()), ["ann"]);
+    assert!(stream.take_errors().is_empty());
+}
+
+// Helpers
+
+fn welcomed(outcomes: Vec<Result<String, ProcessError>>) -> Vec<String> {
+    outcomes.into_iter().map(Result::unwrap).collect()
+}
+
+fn stream() -> (TempDir, Stream) {
+    let dir = TempDir::new().expect("temp dir");
+    let stream = Stream::builder(&dir.path().to_string_lossy())
+        .open()
+        .expect("stream");
+
+    (dir, stream)
+}
@@ -0,0 +1,244 @@
+#![feature(associated_type_defaults)]
+#![feature(if_let_guard)]
+#![feature(once_cell_try)]
+
+use eventric_model::{
+    Enactor as _,
+    action::{
+        Act,
+        Action,
+    },
+    error::{
+        Error,
+        ProjectionError,
+    },
+    event::Event,
+    projection::Projection,
+};
+use eventric_stream::stream::Stream;
+use fancy_constructor::new;
+use revision::revisioned;
+use tempfile::TempDir;
+use thiserror::Error;
+
+// =================================================================================================
+// Projection
+// =================================================================================================
+
+// Events
+
+#[revisioned(revision = 1)]
+#[derive(new, Event, Debug)]
+#[event(identifier(course_opened), tags(course(&this.course)))]
+pub struct CourseOpened {
+    #[new(into)]
+    pub course: String,
+    pub seats: u64,
+}
+
+#[revisioned(revision = 1)]
+#[derive(new, Event, Debug)]
+#[event(identifier(course_closed), tags(course(&this.course)))]
+pub struct CourseClosed {
+    #[new(into)]
+    pub course: String,
+}
+
+// Projections
+
+#[derive(Projection, Debug)]
+#[projection(
+    select(
+        events(CourseOpened, CourseClosed),
+        filter(course(this.course()))
+    ),
+    transition(
+        from(Draft),
+        on(CourseOpened),
+        to(Open),
+        with = |s, e| Lifecycle::Open { course: s.course().to_owned(), seats: e.seats }
+    ),
+    transition(from(Open), on(CourseClosed), to(Closed))
+)]
+pub enum Lifecycle {
+    Draft { course: String },
+    Open { course: String, seats: u64 },
+    Closed { course: String },
+}
+
+impl Lifecycle {
+    fn course(&self) -> &str {
+        match self {
+            Self::Draft { course } | Self::Open { course, .. } | Self::Closed { course } => course,
+        }
+    }
+}
+
+#[derive(new, Projection, Debug)]
+#[projection(
+    select(
+        events(CourseOpened, CourseClosed),
+        filter(course(&this.course))
+    )
+)]
+pub struct Activity {
+    #[new(into)]
+    pub course: String,
+    #[new(default)]
+    #[projection(exists(CourseOpened))]
+    pub opened: bool,
+    #[new(default)]
+    #[projection(count(CourseOpened, CourseClosed))]
+    pub changes: u8,
+}
+
+// Actions
+
+#[derive(new, Action, Debug)]
+#[action(projection(Lifecycle: Lifecycle::Draft { course: this.course.clone() }))]
+pub struct Open {
+    #[new(into)]
+    pub course: String,
+    pub seats: u64,
+}
+
+impl Act for Open {
+    type Err = ProjectionTestError;
+
+    fn action(&mut self, context: &mut Self::Context) -> Result<Self::Ok, Self::Err> {
+        context.append(&CourseOpened::new(&self.course, self.seats))?;
+
+        Ok(())
+    }
+}
+
+#[derive(new, Action, Debug)]
+#[action(projection(Lifecycle: Lifecycle::Draft { course: this.course.clone() }))]
+pub struct Close {
+    #[new(into)]
+    pub course: String,
+}
+
+impl Act for Close {
+    type Err = ProjectionTestError;
+    type Ok = String;
+
+    fn action(&mut self, context: &mut Self::Context) -> Result<Self::Ok, Self::Err> {
+        let state = format!("{:?}", context.lifecycle);
+
+        context.append(&CourseClosed::new(&self.course))?;
+
+        Ok(state)
+    }
+}
+
+#[derive(new, Action, Clone, Debug)]
+#[action(projection(Activity: Activity::new(&this.course)))]
+pub struct Reopen {
+    #[new(into)]
+    pub course: String,
+}
+
+impl Act for Reopen {
+    type Err = ProjectionTestError;
+    type Ok = (bool, u8);
+
+    fn action(&mut self, context: &mut Self::Context) -> Result<Self::Ok, Self::Err> {
+        let activity = (context.activity.opened, context.activity.changes);
+
+        context.append(&CourseOpened::new(&self.course, 0))?;
+
+        Ok(activity)
+    }
+}
+
+// Errors
+
+#[derive(Debug, Error)]
+pub enum ProjectionTestError {
+    #[error(transparent)]
+    Model(#[from] eventric_model::error::Error),
+    #[error(transparent)]
+    Stream(#[from] eventric_stream::error::Error),
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Tests
+
+#[test]
+fn transitions_build_target_states() {
+    let (_dir, mut stream) = stream();
+
+    stream.enact(Open::new("cs101", 30)).unwrap();
+
+    let state = stream.enact(Close::new("cs101")).unwrap();
+
+    assert_eq!(state, r#"Open { course: "cs101", seats: 30 }"#);
+}
+
+#[test]
+fn transitions_carry_shared_fields_without_with() {
+    let (_dir, mut stream) = stream();
+
+    stream.enact(Open::new("cs101", 30)).unwrap();
+    stream.enact(Close::new("cs101")).unwrap();
+
+    let state = stream.enact(Close::new("cs101")).unwrap();
+
+    assert_eq!(state, r#"Closed { course: "cs101" }"#);
+}
+
+#[test]
+fn uncovered_transitions_fail() {
+    let (_dir, mut stream) = stream();
+
+    stream.enact(Open::new("cs101", 30)).unwrap();
+    stream.enact(Close::new("cs101")).unwrap();
+    stream.enact(Close::new("cs101")).unwrap();
+
+    let error = stream.enact(Close::new("cs101")).unwrap_err();
+
+    assert!(matches!(
+        error,
+        ProjectionTestError::Model(Error::Projection(ProjectionError { .. }))
+    ));
+    assert!(
+        format!("{:?}", std::error::Error::source(&error).unwrap()).contains("state: \"Closed\"")
+    );
+}
+
+#[test]
+fn counted_and_existing_fields_track_events() {
+    let (_dir, mut stream) = stream();
+
+    assert_eq!(stream.enact(Reopen::new("cs101")).unwrap(), (false, 0));
+
+    stream.enact(Close::new("cs101")).unwrap();
+
+    assert_eq!(stream.enact(Reopen::new("cs101")).unwrap(), (true, 2));
+    assert_eq!(stream.enact(Reopen::new("cs102")).unwrap(), (false, 0));
+}
+
+#[test]
+fn counted_and_existing_fields_track_candidate_events() {
+    let (_dir, mut stream) = stream();
+
+    let results = stream.enact_batch([Reopen::new("cs101"), Reopen::new("cs101")]);
+
+    assert!(matches!(results.as_slice(), [
+        Ok((false, 0)),
+        Ok((true, 1))
+    ]));
+}
+
+// Helpers
+
+fn stream() -> (TempDir, Stream) {
+    let dir = TempDir::new().expect("temp dir");
+    let stream = Stream::builder(&dir.path().to_string_lossy())
+        .open()
+        .expect("stream");
+
+    (dir, stream)
+}
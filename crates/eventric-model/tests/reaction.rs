@@ -0,0 +1,263 @@
+#![feature(associated_type_defaults)]
+#![feature(if_let_guard)]
+#![feature(once_cell_try)]
+
+use std::sync::{
+    Arc,
+    Mutex,
+    mpsc,
+};
+
+use eventric_model::{
+    Enactor as _,
+    action::{
+        Act,
+        Action,
+    },
+    event::Event,
+    projection::Projection,
+    reaction::{
+        Reacting,
+        Reaction,
+    },
+};
+use eventric_stream::stream::Stream;
+use fancy_constructor::new;
+use revision::revisioned;
+use tempfile::TempDir;
+use thiserror::Error;
+
+// =================================================================================================
+// Reaction
+// =================================================================================================
+
+// Events
+
+#[revisioned(revision = 1)]
+#[derive(new, Event, Clone, Debug, PartialEq)]
+#[event(identifier(student_subscribed), tags(course(&this.course)))]
+pub struct StudentSubscribed {
+    #[new(into)]
+    pub course: String,
+    #[new(into)]
+    pub student: String,
+}
+
+#[revisioned(revision = 1)]
+#[derive(new, Event, Debug)]
+#[event(identifier(course_full), tags(course(&this.course)))]
+pub struct CourseFull {
+    #[new(into)]
+    pub course: String,
+}
+
+#[revisioned(revision = 1)]
+#[derive(new, Event, Debug)]
+#[event(identifier(student_subscribed), tags(course(&this.course)))]
+pub struct MalformedStudentSubscribed {
+    #[new(into)]
+    pub course: String,
+}
+
+// Projections
+
+#[derive(new, Projection, Debug)]
+#[projection(
+    select(
+        events(StudentSubscribed),
+        filter(course(&this.course))
+    )
+)]
+pub struct Subscriptions {
+    #[new(into)]
+    pub course: String,
+    #[new(default)]
+    #[projection(count(StudentSubscribed))]
+    pub count: u64,
+}
+
+// Actions
+
+#[derive(new, Action, Debug)]
+#[action(
+    projection(Subscriptions: Subscriptions::new(&this.course)),
+    forbid(|_, context| context.subscriptions.count >= 2, ReactionTestError::Full)
+)]
+pub struct Subscribe {
+    #[new(into)]
+    pub course: String,
+    #[new(into)]
+    pub student: String,
+}
+
+impl Act for Subscribe {
+    type Err = ReactionTestError;
+
+    fn action(&mut self, context: &mut Self::Context) -> Result<Self::Ok, Self::Err> {
+        context.append(&StudentSubscribed::new(&self.course, &self.student))?;
+
+        if context.subscriptions.count == 1 {
+            context.append(&CourseFull::new(&self.course))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(new, Action, Debug)]
+#[action(projection(Subscriptions: Subscriptions::new(&this.course)))]
+pub struct Corrupt {
+    #[new(into)]
+    pub course: String,
+}
+
+impl Act for Corrupt {
+    type Err = ReactionTestError;
+
+    fn action(&mut self, context: &mut Self::Context) -> Result<Self::Ok, Self::Err> {
+        context.append(&MalformedStudentSubscribed::new(&self.course))?;
+
+        Ok(())
+    }
+}
+
+// Errors
+
+#[derive(Debug, Error)]
+pub enum ReactionTestError {
+    #[error("Course Full")]
+    Full,
+    #[error(transparent)]
+    Model(#[from] eventric_model::error::Error),
+    #[error(transparent)]
+    Stream(#[from] eventric_stream::error::Error),
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Tests
+
+#[test]
+fn reactions_receive_typed_events_after_append() {
+    let (_dir, stream) = stream();
+
+    let reactions = Arc::new(Mutex::new(Vec::new()));
+    let recorded = Arc::clone(&reactions);
+
+    let mut stream = Reacting::new(stream).on(move |reaction: Reaction<StudentSubscribed>| {
+        recorded.lock().unwrap().push(reaction);
+    });
+
+    stream.enact(Subscribe::new("cs101", "ann")).unwrap();
+    stream.enact(Subscribe::new("cs101", "bob")).unwrap();
+
+    assert!(matches!(
+        stream.enact(Subscribe::new("cs101", "cat")),
+        Err(ReactionTestError::Full)
+    ));
+
+    let reactions = reactions.lock().unwrap();
+
+    assert_eq!(
+        reactions
+            .iter()
+            .map(|reaction| reaction.student.as_str())
+            .collect::<Vec<_>>(),
+        ["ann", "bob"]
+    );
+    assert!(reactions[0].position() < reactions[1].position());
+    assert_eq!(reactions[0].tags().len(), 1);
+}
+
+#[test]
+fn reactions_share_the_position_returned_by_their_append() {
+    let (_dir, stream) = stream();
+
+    let positions = Arc::new(Mutex::new(Vec::new()));
+    let subscribed = Arc::clone(&positions);
+    let full = Arc::clone(&positions);
+
+    let mut stream = Reacting::new(stream)
+        .on(move |reaction: Reaction<StudentSubscribed>| {
+            subscribed.lock().unwrap().push(*reaction.position());
+        })
+        .on(move |reaction: Reaction<CourseFull>| {
+            full.lock().unwrap().push(*reaction.position());
+        });
+
+    stream.enact(Subscribe::new("cs101", "ann")).unwrap();
+    stream.enact(Subscribe::new("cs101", "bob")).unwrap();
+
+    let positions = positions.lock().unwrap();
+
+    assert_eq!(positions.len(), 3);
+    assert_ne!(positions[0], positions[1]);
+    assert_eq!(positions[1], positions[2]);
+}
+
+#[test]
+fn reactions_are_queued() {
+    let (_dir, stream) = stream();
+
+    let (sender, receiver) = mpsc::channel();
+
+    let mut stream = Reacting::new(stream).queue::<StudentSubscribed>(sender);
+
+    let results = stream.enact_batch([
+        Subscribe::new("cs101", "ann"),
+        Subscribe::new("cs102", "bob"),
+    ]);
+
+    assert!(matches!(results.as_slice(), [Ok(()), Ok(())]));
+    assert_eq!(
+        receiver
+            .try_iter()
+            .map(Reaction::into_inner)
+            .collect::<Vec<_>>(),
+        [
+            StudentSubscribed::new("cs101", "ann"),
+            StudentSubscribed::new("cs102", "bob")
+        ]
+    );
+}
+
+#[test]
+fn reaction_errors_are_bounded() {
+    let (_dir, stream) = stream();
+
+    let positions = Arc::new(Mutex::new(Vec::new()));
+    let recorded = Arc::clone(&positions);
+
+    let mut stream = Reacting::new(stream)
+        .on(|_: Reaction<StudentSubscribed>| {})
+        .on(move |reaction: Reaction<MalformedStudentSubscribed>| {
+            recorded.lock().unwrap().push(*reaction.position());
+        })
+        .error_capacity(2);
+
+    for course in ["cs101", "cs102", "cs103"] {
+        stream.enact(Corrupt::new(course)).unwrap();
+    }
+
+    let errors = stream.take_errors();
+
+    assert_eq!(
+        errors
+            .iter()
+            .map(|error| error.position)
+            .collect::<Vec<_>>(),
+        positions.lock().unwrap()[1..]
+    );
+    assert!(stream.take_errors().is_empty());
+}
+
+// Helpers
+
+fn stream() -> (TempDir, Stream) {
+    let dir = TempDir::new().expect("temp dir");
+    let stream = Stream::builder(&dir.path().to_string_lossy())
+        .open()
+        .expect("stream");
+
+    (dir, stream)
+}
@@ -0,0 +1,194 @@
+#![feature(associated_type_defaults)]
+#![feature(if_let_guard)]
+#![feature(once_cell_try)]
+
+use eventric_model::{
+    DynEnactor,
+    DynEnactorExt as _,
+    RecordingEnactor,
+    Registry,
+    action::{
+        Act,
+        Action,
+    },
+    error::{
+        Error,
+        RegistryError,
+    },
+    event::Event,
+    projection::Projection,
+};
+use eventric_stream::stream::Stream;
+use fancy_constructor::new;
+use revision::revisioned;
+use tempfile::TempDir;
+use thiserror::Error;
+
+// =================================================================================================
+// Registry
+// =================================================================================================
+
+// Events
+
+#[revisioned(revision = 1)]
+#[derive(new, Event, Debug)]
+#[event(identifier(account_opened), tags(account(&this.account)))]
+pub struct AccountOpened {
+    #[new(into)]
+    pub account: String,
+}
+
+// Projections
+
+#[derive(new, Projection, Debug)]
+#[projection(
+    select(
+        events(AccountOpened),
+        filter(account(&this.account))
+    )
+)]
+pub struct AccountExists {
+    #[new(into)]
+    pub account: String,
+    #[new(default)]
+    #[projection(exists(AccountOpened))]
+    pub exists: bool,
+}
+
+// Actions
+
+#[derive(new, Action, Debug, PartialEq)]
+#[action(
+    projection(AccountExists: AccountExists::new(&this.account)),
+    forbid(|_, context| context.account_exists.exists, RegistryTestError::Exists)
+)]
+pub struct Open {
+    #[new(into)]
+    pub account: String,
+}
+
+impl Act for Open {
+    type Err = RegistryTestError;
+    type Ok = String;
+
+    fn action(&mut self, context: &mut Self::Context) -> Result<Self::Ok, Self::Err> {
+        context.append(&AccountOpened::new(&self.account))?;
+
+        Ok(self.account.clone())
+    }
+}
+
+#[derive(new, Action, Debug)]
+#[action(projection(AccountExists: AccountExists::new(&this.account)))]
+pub struct Close {
+    #[new(into)]
+    pub account: String,
+}
+
+impl Act for Close {
+    type Err = RegistryTestError;
+
+    fn action(&mut self, _: &mut Self::Context) -> Result<Self::Ok, Self::Err> {
+        Ok(())
+    }
+}
+
+// Services
+
+pub fn open_accounts(
+    enactor: &mut dyn DynEnactor,
+    accounts: &[&str],
+) -> Vec<Result<String, RegistryTestError>> {
+    accounts
+        .iter()
+        .map(|account| enactor.enact(Open::new(*account)))
+        .collect()
+}
+
+// Errors
+
+#[derive(Debug, Error)]
+pub enum RegistryTestError {
+    #[error("Account Exists")]
+    Exists,
+    #[error(transparent)]
+    Model(#[from] Error),
+    #[error(transparent)]
+    Stream(#[from] eventric_stream::error::Error),
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Tests
+
+#[test]
+fn registries_enact_registered_actions() {
+    let (_dir, stream) = stream();
+
+    let mut registry = Registry::new(stream).register::<Open>();
+
+    let results = open_accounts(&mut registry, &["ann", "bob", "ann"]);
+
+    assert!(matches!(results.as_slice(), [
+        Ok(_),
+        Ok(_),
+        Err(RegistryTestError::Exists)
+    ]));
+}
+
+#[test]
+fn registries_reject_unregistered_actions() {
+    let (_dir, stream) = stream();
+
+    let mut enactor: Box<dyn DynEnactor> = Box::new(Registry::new(stream).register::<Open>());
+
+    assert!(matches!(
+        enactor.enact(Close::new("ann")),
+        Err(RegistryTestError::Model(Error::Registry(RegistryError::Unhandled(name))))
+            if name.ends_with("Close")
+    ));
+}
+
+#[test]
+fn recording_enactors_return_scripted_results() {
+    let mut enactor = RecordingEnactor::new()
+        .script::<Open>(Ok(String::from("ann")))
+        .script::<Open>(Err(RegistryTestError::Exists));
+
+    let results = open_accounts(&mut enactor, &["ann", "ann"]);
+
+    assert!(matches!(
+        results.as_slice(),
+        [Ok(account), Err(RegistryTestError::Exists)] if account == "ann"
+    ));
+    assert_eq!(enactor.recorded::<Open>().collect::<Vec<_>>(), [
+        &Open::new("ann"),
+        &Open::new("ann")
+    ]);
+}
+
+#[test]
+fn recording_enactors_record_unscripted_actions() {
+    let mut enactor = RecordingEnactor::new();
+
+    assert!(matches!(
+        enactor.enact(Close::new("ann")),
+        Err(RegistryTestError::Model(Error::Registry(
+            RegistryError::Unhandled(_)
+        )))
+    ));
+    assert_eq!(enactor.actions.len(), 1);
+    assert!(enactor.actions[0].is::<Close>());
+    assert_eq!(enactor.recorded::<Open>().count(), 0);
+}
+
+// Helpers
+
+fn stream() -> (TempDir, Stream) {
+    let dir = TempDir::new().expect("temp dir");
+    let stream = Stream::builder(&dir.path().to_string_lossy())
+        .open()
+        .expect("stream");
+
+    (dir, stream)
+}
@@ -0,0 +1,268 @@
+#![feature(associated_type_defaults)]
+#![feature(if_let_guard)]
+#![feature(once_cell_try)]
+
+use std::{
+    sync::{
+        Arc,
+        Mutex,
+    },
+    time::{
+        Duration,
+        SystemTime,
+    },
+};
+
+use eventric_model::{
+    Enactor as _,
+    action::{
+        Act,
+        Action,
+    },
+    event::Event,
+    reaction::{
+        Reacting,
+        Reaction,
+    },
+    schedule::{
+        ManualClock,
+        ScheduleTimer,
+        Scheduler,
+        TimerScheduled,
+    },
+};
+use eventric_stream::stream::Stream;
+use fancy_constructor::new;
+use revision::revisioned;
+use tempfile::TempDir;
+use thiserror::Error;
+
+// =================================================================================================
+// Schedule
+// =================================================================================================
+
+const HOLD: Duration = Duration::from_hours(48);
+
+// Events
+
+#[revisioned(revision = 1)]
+#[derive(new, Event, Debug)]
+#[event(
+    identifier(seat_reserved),
+    tags(seat(&this.seat)),
+    schedule(name = "release", key = this.seat, after = HOLD)
+)]
+pub struct SeatReserved {
+    #[new(into)]
+    pub seat: String,
+}
+
+#[revisioned(revision = 1)]
+#[derive(new, Event, Debug)]
+#[event(
+    identifier(seat_confirmed),
+    tags(seat(&this.seat)),
+    cancel(name = "release", key = this.seat)
+)]
+pub struct SeatConfirmed {
+    #[new(into)]
+    pub seat: String,
+}
+
+#[revisioned(revision = 1)]
+#[derive(new, Event, Debug)]
+#[event(identifier(seat_released), tags(seat(&this.seat)))]
+pub struct SeatReleased {
+    #[new(into)]
+    pub seat: String,
+}
+
+// Actions
+
+#[derive(new, Action, Debug)]
+pub struct ReserveSeat {
+    #[new(into)]
+    pub seat: String,
+}
+
+impl Act for ReserveSeat {
+    type Err = ScheduleTestError;
+
+    fn action(&mut self, context: &mut Self::Context) -> Result<Self::Ok, Self::Err> {
+        context.append(&SeatReserved::new(&self.seat))?;
+
+        Ok(())
+    }
+}
+
+#[derive(new, Action, Debug)]
+pub struct ConfirmSeat {
+    #[new(into)]
+    pub seat: String,
+}
+
+impl Act for ConfirmSeat {
+    type Err = ScheduleTestError;
+
+    fn action(&mut self, context: &mut Self::Context) -> Result<Self::Ok, Self::Err> {
+        context.append(&SeatConfirmed::new(&self.seat))?;
+
+        Ok(())
+    }
+}
+
+#[derive(new, Action, Debug)]
+pub struct ReleaseSeat {
+    #[new(into)]
+    pub seat: String,
+}
+
+impl Act for ReleaseSeat {
+    type Err = ScheduleTestError;
+
+    fn action(&mut self, context: &mut Self::Context) -> Result<Self::Ok, Self::Err> {
+        context.append(&SeatReleased::new(&self.seat))?;
+
+        Ok(())
+    }
+}
+
+// Errors
+
+#[derive(Debug, Error)]
+pub enum ScheduleTestError {
+    #[error(transparent)]
+    Model(#[from] eventric_model::error::Error),
+    #[error(transparent)]
+    Stream(#[from] eventric_stream::error::Error),
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Tests
+
+#[test]
+fn deadlines_fire_once_due() {
+    let (_dir, mut stream) = stream();
+
+    let clock = ManualClock::new(SystemTime::now());
+    let mut scheduler = scheduler(clock.clone());
+
+    stream.enact(ReserveSeat::new("a1")).unwrap();
+
+    assert_eq!(scheduler.run(&mut stream).unwrap(), 0);
+
+    clock.advance(HOLD / 2);
+
+    assert_eq!(scheduler.run(&mut stream).unwrap(), 0);
+
+    clock.advance(HOLD);
+
+    assert_eq!(scheduler.run(&mut stream).unwrap(), 1);
+    assert_eq!(scheduler.run(&mut stream).unwrap(), 0);
+}
+
+#[test]
+fn deadlines_are_measured_from_the_source_event() {
+    let (_dir, stream) = stream();
+
+    let timers = Arc::new(Mutex::new(Vec::new()));
+    let recorded = Arc::clone(&timers);
+
+    let mut stream = Reacting::new(stream).on(move |timer: Reaction<TimerScheduled>| {
+        recorded.lock().unwrap().push(timer.due());
+    });
+
+    let before = SystemTime::now();
+
+    stream.enact(ReserveSeat::new("a1")).unwrap();
+
+    let after = SystemTime::now();
+    let clock = ManualClock::new(after + 30 * HOLD);
+    let mut scheduler = scheduler(clock);
+
+    assert_eq!(scheduler.run(&mut stream).unwrap(), 1);
+
+    let timers = timers.lock().unwrap();
+
+    assert_eq!(timers.len(), 1);
+    assert!(timers[0] + Duration::from_millis(1) >= before + HOLD);
+    assert!(timers[0] <= after + HOLD);
+}
+
+#[test]
+fn cancelled_deadlines_do_not_fire() {
+    let (_dir, mut stream) = stream();
+
+    let clock = ManualClock::new(SystemTime::now());
+    let mut scheduler = scheduler(clock.clone());
+
+    stream.enact(ReserveSeat::new("a1")).unwrap();
+
+    assert_eq!(scheduler.run(&mut stream).unwrap(), 0);
+
+    stream.enact(ConfirmSeat::new("a1")).unwrap();
+    clock.advance(2 * HOLD);
+
+    assert_eq!(scheduler.run(&mut stream).unwrap(), 0);
+}
+
+#[test]
+fn timers_are_scheduled_once_per_source() {
+    let (_dir, mut stream) = stream();
+
+    let mut timer = TimerScheduled::new("release", "a1", SystemTime::now() + HOLD);
+
+    timer.source = Some(0);
+
+    let schedule = ScheduleTimer::<eventric_model::error::Error>::new;
+
+    assert!(stream.enact(schedule(timer.clone())).unwrap());
+    assert!(!stream.enact(schedule(timer.clone())).unwrap());
+
+    timer.source = Some(1);
+
+    assert!(stream.enact(schedule(timer)).unwrap());
+}
+
+#[test]
+fn unhandled_timers_are_rejected() {
+    let (_dir, stream) = stream();
+
+    let timers = Arc::new(Mutex::new(0));
+    let recorded = Arc::clone(&timers);
+
+    let mut stream = Reacting::new(stream).on(move |_: Reaction<TimerScheduled>| {
+        *recorded.lock().unwrap() += 1;
+    });
+
+    let clock = ManualClock::new(SystemTime::now());
+    let mut scheduler = Scheduler::new(clock.clone()).deadlines::<SeatReserved>();
+
+    stream.enact(ReserveSeat::new("a1")).unwrap();
+    clock.advance(2 * HOLD);
+
+    assert_eq!(scheduler.run(&mut stream).unwrap(), 0);
+    assert_eq!(*timers.lock().unwrap(), 0);
+}
+
+// Helpers
+
+fn scheduler<S>(clock: ManualClock) -> Scheduler<S, ManualClock>
+where
+    S: eventric_model::Enactor + eventric_stream::stream::iterate::IterateSelect,
+{
+    Scheduler::new(clock)
+        .deadlines::<SeatReserved>()
+        .deadlines::<SeatConfirmed>()
+        .on("release", |seat| ReleaseSeat::new(seat))
+}
+
+fn stream() -> (TempDir, Stream) {
+    let dir = TempDir::new().expect("temp dir");
+    let stream = Stream::builder(&dir.path().to_string_lossy())
+        .open()
+        .expect("stream");
+
+    (dir, stream)
+}
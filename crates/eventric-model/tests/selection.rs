@@ -0,0 +1,188 @@
+#![feature(associated_type_defaults)]
+#![feature(if_let_guard)]
+#![feature(once_cell_try)]
+
+use eventric_model::{
+    Enactor as _,
+    action::{
+        Act,
+        Action,
+    },
+    event::{
+        Event,
+        Identifier as _,
+    },
+    projection::{
+        Projection,
+        Select,
+        Selectors,
+    },
+};
+use eventric_stream::{
+    event::tag,
+    stream::{
+        Stream,
+        select::{
+            Selection,
+            Selector,
+        },
+    },
+};
+use fancy_constructor::new;
+use revision::revisioned;
+use tempfile::TempDir;
+use thiserror::Error;
+
+// =================================================================================================
+// Selection
+// =================================================================================================
+
+// Events
+
+#[revisioned(revision = 1)]
+#[derive(new, Event, Debug)]
+#[event(
+    identifier(student_enrolled),
+    tags(course(&this.course), student(&this.student))
+)]
+pub struct StudentEnrolled {
+    #[new(into)]
+    pub course: String,
+    #[new(into)]
+    pub student: String,
+}
+
+// Projections
+
+#[derive(new, Projection, Debug)]
+#[projection(
+    select(
+        name = "by_course",
+        events(StudentEnrolled),
+        filter(course(&this.course))
+    ),
+    select(
+        name = "by_student",
+        events(StudentEnrolled),
+        filter(student(&this.student))
+    ),
+    on(StudentEnrolled => |s, e| s.selected.push(e.selected().names().collect()))
+)]
+pub struct Enrolments {
+    #[new(into)]
+    pub course: String,
+    #[new(into)]
+    pub student: String,
+    #[new(default)]
+    pub selected: Vec<Vec<&'static str>>,
+}
+
+#[derive(new, Debug)]
+pub struct Unnamed;
+
+impl Select for Unnamed {
+    fn select(&self) -> Result<Selection, eventric_stream::error::Error> {
+        Selection::new([Selector::specifiers([])?])
+    }
+}
+
+// Actions
+
+#[derive(new, Action, Debug)]
+#[action()]
+pub struct Enrol {
+    #[new(into)]
+    pub course: String,
+    #[new(into)]
+    pub student: String,
+}
+
+impl Act for Enrol {
+    type Err = SelectionError;
+
+    fn action(&mut self, context: &mut Self::Context) -> Result<Self::Ok, Self::Err> {
+        context.append(&StudentEnrolled::new(&self.course, &self.student))?;
+
+        Ok(())
+    }
+}
+
+#[derive(new, Action, Debug)]
+#[action(projection(Enrolments: Enrolments::new(&this.course, &this.student)))]
+pub struct Inspect {
+    #[new(into)]
+    pub course: String,
+    #[new(into)]
+    pub student: String,
+}
+
+impl Act for Inspect {
+    type Err = SelectionError;
+    type Ok = Vec<Vec<&'static str>>;
+
+    fn action(&mut self, context: &mut Self::Context) -> Result<Self::Ok, Self::Err> {
+        Ok(context.enrolments.selected.clone())
+    }
+}
+
+// Errors
+
+#[derive(Debug, Error)]
+pub enum SelectionError {
+    #[error(transparent)]
+    Model(#[from] eventric_model::error::Error),
+    #[error(transparent)]
+    Stream(#[from] eventric_stream::error::Error),
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Tests
+
+#[test]
+fn selected_names_follow_matching_selectors() {
+    let (_dir, mut stream) = stream();
+
+    stream.enact(Enrol::new("cs101", "ann")).unwrap();
+    stream.enact(Enrol::new("cs101", "bob")).unwrap();
+    stream.enact(Enrol::new("cs102", "ann")).unwrap();
+
+    let selected = stream.enact(Inspect::new("cs101", "ann")).unwrap();
+
+    assert_eq!(selected, [
+        vec!["by_course", "by_student"],
+        vec!["by_course"],
+        vec!["by_student"],
+    ]);
+}
+
+#[test]
+fn selectors_match_identifiers_and_tags() {
+    let selectors = Enrolments::new("cs101", "ann").selectors().unwrap();
+    let identifier = StudentEnrolled::identifier().unwrap();
+
+    assert!(selectors.matches(identifier, &[tag!(course, "cs101").unwrap()]));
+    assert!(selectors.matches(identifier, &[tag!(student, "ann").unwrap()]));
+    assert!(!selectors.matches(identifier, &[tag!(course, "cs102").unwrap()]));
+}
+
+#[test]
+fn default_selectors_match_any_event() {
+    let selectors = Unnamed::new().selectors().unwrap();
+    let identifier = StudentEnrolled::identifier().unwrap();
+
+    assert!(selectors.matches(identifier, &[]));
+    assert!(selectors.matches(identifier, &[tag!(course, "cs101").unwrap()]));
+    assert!(Selectors::any().matches(identifier, &[]));
+}
+
+// Helpers
+
+fn stream() -> (TempDir, Stream) {
+    let dir = TempDir::new().expect("temp dir");
+    let stream = Stream::builder(&dir.path().to_string_lossy())
+        .open()
+        .expect("stream");
+
+    (dir, stream)
+}
@@ -0,0 +1,93 @@
+#![feature(if_let_guard)]
+#![feature(once_cell_try)]
+
+use std::io;
+
+use eventric_model::{
+    Enactor as _,
+    action::{
+        Act,
+        Action,
+    },
+    event::Event,
+    outbox::{
+        FileSink,
+        OutboxMessage,
+        Relay,
+    },
+    process::MemoryCheckpoint,
+};
+use eventric_stream::stream::Stream;
+use fancy_constructor::new;
+use revision::revisioned;
+use thiserror::Error;
+
+// =================================================================================================
+// Course Announcements
+// =================================================================================================
+
+// This example records an integration message in the outbox as part of the
+// same append as the domain event, and relays it to other systems afterwards.
+
+// Events
+
+#[revisioned(revision = 1)]
+#[derive(new, Event, Debug)]
+#[event(
+    identifier(course_announced),
+    tags(course(&this.id))
+)]
+pub struct CourseAnnounced {
+    #[new(into)]
+    pub id: String,
+}
+
+// Actions
+
+#[derive(new, Action, Debug)]
+pub struct AnnounceCourse {
+    #[new(into)]
+    id: String,
+}
+
+impl Act for AnnounceCourse {
+    type Err = AnnouncementError;
+
+    fn action(&mut self, context: &mut Self::Context) -> Result<Self::Ok, Self::Err> {
+        context.append(&CourseAnnounced::new(&self.id))?;
+        context.append(&OutboxMessage::new(
+            "announcements",
+            format!("course {} announced", self.id),
+        ))?;
+
+        Ok(())
+    }
+}
+
+// Errors
+
+#[derive(Debug, Error)]
+pub enum AnnouncementError {
+    #[error(transparent)]
+    Model(#[from] eventric_model::error::Error),
+    #[error(transparent)]
+    Stream(#[from] eventric_stream::error::Error),
+}
+
+// Example
+
+pub fn main() -> Result<(), AnnouncementError> {
+    let mut stream = Stream::builder("./temp").open()?;
+
+    let result = stream.enact(AnnounceCourse::new("cs:101"));
+
+    println!("Announce Course Result: {result:?}");
+
+    let mut relay =
+        Relay::new(FileSink::new(io::stdout()), MemoryCheckpoint::new()).topic("announcements");
+    let result = relay.run(&stream);
+
+    println!("Relay Outbox Result: {result:?}");
+
+    Ok(())
+}
@@ -1,12 +1,22 @@
 use derive_more::Debug;
-use eventric_model::action::{
-    Act,
-    Action,
+use eventric_model::{
+    action::{
+        Act,
+        Action,
+        Context,
+        Decide,
+    },
+    event::{
+        AnyEvent,
+        Events,
+    },
+    projection::library::Count,
 };
-use eventric_stream::error::Error;
+use eventric_stream::event;
 use fancy_constructor::new;
 
 use crate::{
+    errors::CourseError,
     events::{
         CourseCapacityChanged,
         CourseDefined,
@@ -29,7 +39,8 @@ use crate::{
 
 #[derive(new, Action, Debug)]
 #[action(
-    projection(CourseExists: CourseExists::new(&this.id))
+    projection(CourseExists: CourseExists::new(&this.id)),
+    forbid(course_exists.exists, CourseError::CourseAlreadyExists)
 )]
 pub struct DefineCourse {
     #[new(into)]
@@ -38,13 +49,9 @@ pub struct DefineCourse {
 }
 
 impl Act for DefineCourse {
-    type Err = Error;
+    type Err = CourseError;
 
     fn action(&mut self, context: &mut Self::Context) -> Result<Self::Ok, Self::Err> {
-        if context.course_exists.exists {
-            return Err(Error::data("Course Already Exists"));
-        }
-
         context.append(&CourseDefined::new(&self.id, self.capacity))?;
 
         Ok(())
@@ -53,8 +60,22 @@ impl Act for DefineCourse {
 
 #[derive(new, Action, Debug)]
 #[action(
+    decide,
     projection(CourseExists: CourseExists::new(&this.id)),
-    projection(CourseCapacity: CourseCapacity::new(&this.id))
+    projection(CourseCapacity: CourseCapacity::new(&this.id)),
+    projection(
+        Count<StudentSubscribedToCourse> @ course_subscriptions:
+            ?|this| event::tag!(course, &this.id).map(|tag| Count::new([tag]))
+    ),
+    require(course_exists.exists, CourseError::CourseDoesNotExist),
+    forbid(
+        |this, context| context.course_capacity.capacity == this.new_capacity,
+        CourseError::CurrentCourseCapacityEqualsNewCapacity
+    ),
+    forbid(
+        |this, context| context.course_subscriptions.count > u64::from(this.new_capacity),
+        CourseError::NewCourseCapacityBelowSubscriptions
+    )
 )]
 pub struct ChangeCourseCapacity {
     #[new(into)]
@@ -62,31 +83,29 @@ pub struct ChangeCourseCapacity {
     new_capacity: u8,
 }
 
-impl Act for ChangeCourseCapacity {
-    type Err = Error;
-
-    fn action(&mut self, context: &mut Self::Context) -> Result<Self::Ok, Self::Err> {
-        if !context.course_exists.exists {
-            return Err(Error::data("Course Does Not Exist"));
-        }
-
-        if context.course_capacity.capacity == self.new_capacity {
-            return Err(Error::data("Current Course Capacity Equals New Capacity"));
-        }
-
-        context.append(&CourseCapacityChanged::new(&self.id, self.new_capacity))?;
+impl Decide for ChangeCourseCapacity {
+    type Err = CourseError;
 
-        Ok(())
+    fn decide(&self, _: &Self::Context) -> Result<Vec<AnyEvent>, Self::Err> {
+        Ok(vec![
+            CourseCapacityChanged::new(&self.id, self.new_capacity).into(),
+        ])
     }
 }
 
-#[derive(new, Action, Debug)]
+#[derive(new, Action, Clone, Debug)]
 #[action(
-    projection(CourseExists: CourseExists::new(&this.course_id)),
-    projection(CourseCapacity: CourseCapacity::new(&this.course_id)),
-    projection(NumberOfCourseSubscriptions: NumberOfCourseSubscriptions::new(&this.course_id)),
-    projection(NumberOfStudentSubscriptions: NumberOfStudentSubscriptions::new(&this.student_id)),
-    projection(StudentAlreadySubscribed: StudentAlreadySubscribed::new(&this.course_id, &this.student_id))
+    context = SubscribeStudentToCourseContext,
+    require(course_exists.exists, CourseError::CourseDoesNotExist),
+    forbid(
+        |_, context| context.number_of_course_subscriptions.count >= context.course_capacity.capacity,
+        CourseError::CourseFullyBooked
+    ),
+    forbid(student_already_subscribed.subscribed, CourseError::StudentAlreadySubscribed),
+    forbid(
+        |_, context| context.number_of_student_subscriptions.count >= 5,
+        CourseError::StudentReachedCourseLimit
+    )
 )]
 pub struct SubscribeStudentToCourse {
     #[new(into)]
@@ -95,26 +114,26 @@ pub struct SubscribeStudentToCourse {
     student_id: String,
 }
 
+#[derive(Context, Debug)]
+#[context(action = SubscribeStudentToCourse)]
+pub struct SubscribeStudentToCourseContext {
+    events: Events,
+    #[projection(init = CourseExists::new(&action.course_id))]
+    course_exists: CourseExists,
+    #[projection(init = CourseCapacity::new(&action.course_id))]
+    course_capacity: CourseCapacity,
+    #[projection(init = NumberOfCourseSubscriptions::new(&action.course_id))]
+    number_of_course_subscriptions: NumberOfCourseSubscriptions,
+    #[projection(init = NumberOfStudentSubscriptions::new(&action.student_id))]
+    number_of_student_subscriptions: NumberOfStudentSubscriptions,
+    #[projection(init = StudentAlreadySubscribed::new(&action.course_id, &action.student_id))]
+    student_already_subscribed: StudentAlreadySubscribed,
+}
+
 impl Act for SubscribeStudentToCourse {
-    type Err = Error;
+    type Err = CourseError;
 
     fn action(&mut self, context: &mut Self::Context) -> Result<Self::Ok, Self::Err> {
-        if !context.course_exists.exists {
-            return Err(Error::data("Course Does Not Exist"));
-        }
-
-        if context.number_of_course_subscriptions.count >= context.course_capacity.capacity {
-            return Err(Error::data("Course Fully Booked"));
-        }
-
-        if context.student_already_subscribed.subscribed {
-            return Err(Error::data("Student Already Subscribed"));
-        }
-
-        if context.number_of_student_subscriptions.count >= 5 {
-            return Err(Error::data("Student Reached Course Limit"));
-        }
-
         context.append(&StudentSubscribedToCourse::new(
             &self.course_id,
             &self.student_id,
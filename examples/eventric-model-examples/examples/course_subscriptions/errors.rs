@@ -0,0 +1,35 @@
+use thiserror::Error;
+
+// =================================================================================================
+// Course Subscriptions: Errors
+// =================================================================================================
+
+// Action Errors
+
+#[derive(Debug, Error)]
+pub enum CourseError {
+    #[error("Course Already Exists")]
+    CourseAlreadyExists,
+    #[error("Course Does Not Exist")]
+    CourseDoesNotExist,
+    #[error("Course Fully Booked")]
+    CourseFullyBooked,
+    #[error("Current Course Capacity Equals New Capacity")]
+    CurrentCourseCapacityEqualsNewCapacity,
+    #[error("New Course Capacity Below Subscriptions")]
+    NewCourseCapacityBelowSubscriptions,
+    #[error("Student Already Subscribed")]
+    StudentAlreadySubscribed,
+    #[error("Student Reached Course Limit")]
+    StudentReachedCourseLimit,
+    #[error(transparent)]
+    Model(#[from] eventric_model::error::Error),
+    #[error(transparent)]
+    Stream(#[from] eventric_stream::error::Error),
+}
+
+// Projection Errors
+
+#[derive(Debug, Error)]
+#[error("Subscription Count Overflow")]
+pub struct SubscriptionCountOverflow;
@@ -3,19 +3,30 @@
 #![feature(once_cell_try)]
 
 mod actions;
+mod errors;
 mod events;
 mod projections;
 
-use eventric_model::Enactor as _;
+use eventric_model::{
+    Enactor as _,
+    action::composite::Composite,
+    reaction::{
+        Reacting,
+        Reaction,
+    },
+};
 use eventric_stream::{
     error::Error,
     stream::Stream,
 };
 
-use crate::actions::{
-    ChangeCourseCapacity,
-    DefineCourse,
-    SubscribeStudentToCourse,
+use crate::{
+    actions::{
+        ChangeCourseCapacity,
+        DefineCourse,
+        SubscribeStudentToCourse,
+    },
+    events::StudentSubscribedToCourse,
 };
 
 // =================================================================================================
@@ -28,7 +39,13 @@ use crate::actions::{
 // Example
 
 pub fn main() -> Result<(), Error> {
-    let mut stream = Stream::builder("./temp").open()?;
+    let stream = Stream::builder("./temp").open()?;
+    let mut stream = Reacting::new(stream).on(|reaction: Reaction<StudentSubscribedToCourse>| {
+        println!(
+            "Welcome {} to course {}!",
+            reaction.student_id, reaction.course_id
+        );
+    });
 
     let action = DefineCourse::new("cs:101", 30);
     let result = stream.enact(action);
@@ -45,5 +62,28 @@ pub fn main() -> Result<(), Error> {
 
     println!("Subscribe Student To Course Result: {result:?}");
 
+    let actions = ["barbara", "charles", "diana"]
+        .map(|student_id| SubscribeStudentToCourse::new("cs:101", student_id));
+    let results = stream.enact_batch(actions);
+
+    println!("Subscribe Students To Course Results: {results:?}");
+
+    let action = ChangeCourseCapacity::new("cs:101", 2);
+    let result = stream.enact(action);
+
+    println!("Reduce Course Capacity Result: {result:?}");
+
+    let errors = stream.take_errors();
+
+    println!("Reaction Errors: {errors:?}");
+
+    let action = Composite::new(
+        DefineCourse::new("math:110", 10),
+        SubscribeStudentToCourse::new("math:110", "andrew"),
+    );
+    let result = stream.enact(action);
+
+    println!("Define Course And Subscribe Student Result: {result:?}");
+
     Ok(())
 }
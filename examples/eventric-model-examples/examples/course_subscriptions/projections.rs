@@ -1,15 +1,20 @@
 use derive_more::Debug;
 use eventric_model::projection::{
+    Candidate,
     Project,
+    ProjectCandidate,
     Projection,
     ProjectionEvent,
 };
 use fancy_constructor::new;
 
-use crate::events::{
-    CourseCapacityChanged,
-    CourseDefined,
-    StudentSubscribedToCourse,
+use crate::{
+    errors::SubscriptionCountOverflow,
+    events::{
+        CourseCapacityChanged,
+        CourseDefined,
+        StudentSubscribedToCourse,
+    },
 };
 
 // =================================================================================================
@@ -27,23 +32,20 @@ use crate::events::{
 )]
 pub struct CourseExists {
     #[new(default)]
+    #[projection(exists(CourseDefined))]
     pub exists: bool,
     #[new(into)]
     pub id: String,
 }
 
-impl Project<CourseDefined> for CourseExists {
-    fn project(&mut self, _: ProjectionEvent<'_, CourseDefined>) {
-        self.exists = true;
-    }
-}
-
 #[derive(new, Projection, Debug)]
 #[projection(
     select(
         events(CourseDefined, CourseCapacityChanged),
         filter(course(&this.id))
-    )
+    ),
+    on(CourseDefined => |s, e| s.capacity = e.capacity),
+    on(CourseCapacityChanged => |s, e| s.capacity = e.new_capacity)
 )]
 pub struct CourseCapacity {
     #[new(default)]
@@ -52,18 +54,6 @@ pub struct CourseCapacity {
     pub id: String,
 }
 
-impl Project<CourseDefined> for CourseCapacity {
-    fn project(&mut self, event: ProjectionEvent<'_, CourseDefined>) {
-        self.capacity = event.capacity;
-    }
-}
-
-impl Project<CourseCapacityChanged> for CourseCapacity {
-    fn project(&mut self, event: ProjectionEvent<'_, CourseCapacityChanged>) {
-        self.capacity = event.new_capacity;
-    }
-}
-
 #[derive(new, Projection, Debug)]
 #[projection(
     select(
@@ -73,6 +63,7 @@ impl Project<CourseCapacityChanged> for CourseCapacity {
 )]
 pub struct StudentAlreadySubscribed {
     #[new(default)]
+    #[projection(exists(StudentSubscribedToCourse))]
     pub subscribed: bool,
     #[new(into)]
     pub course_id: String,
@@ -80,14 +71,9 @@ pub struct StudentAlreadySubscribed {
     pub student_id: String,
 }
 
-impl Project<StudentSubscribedToCourse> for StudentAlreadySubscribed {
-    fn project(&mut self, _: ProjectionEvent<'_, StudentSubscribedToCourse>) {
-        self.subscribed = true;
-    }
-}
-
 #[derive(new, Projection, Debug)]
 #[projection(
+    candidate,
     select(
         events(StudentSubscribedToCourse),
         filter(course(&this.course_id))
@@ -101,13 +87,32 @@ pub struct NumberOfCourseSubscriptions {
 }
 
 impl Project<StudentSubscribedToCourse> for NumberOfCourseSubscriptions {
-    fn project(&mut self, _: ProjectionEvent<'_, StudentSubscribedToCourse>) {
-        self.count += 1;
+    type Err = SubscriptionCountOverflow;
+
+    fn project(
+        &mut self,
+        _: ProjectionEvent<'_, StudentSubscribedToCourse>,
+    ) -> Result<(), Self::Err> {
+        self.count = self.count.checked_add(1).ok_or(SubscriptionCountOverflow)?;
+
+        Ok(())
+    }
+}
+
+impl ProjectCandidate<StudentSubscribedToCourse> for NumberOfCourseSubscriptions {
+    fn project_candidate(
+        &mut self,
+        _: ProjectionEvent<'_, StudentSubscribedToCourse, Candidate>,
+    ) -> Result<(), Self::Err> {
+        self.count = self.count.checked_add(1).ok_or(SubscriptionCountOverflow)?;
+
+        Ok(())
     }
 }
 
 #[derive(new, Projection, Debug)]
 #[projection(
+    candidate,
     select(
         events(StudentSubscribedToCourse),
         filter(student(&this.student_id))
@@ -121,7 +126,25 @@ pub struct NumberOfStudentSubscriptions {
 }
 
 impl Project<StudentSubscribedToCourse> for NumberOfStudentSubscriptions {
-    fn project(&mut self, _: ProjectionEvent<'_, StudentSubscribedToCourse>) {
-        self.count += 1;
+    type Err = SubscriptionCountOverflow;
+
+    fn project(
+        &mut self,
+        _: ProjectionEvent<'_, StudentSubscribedToCourse>,
+    ) -> Result<(), Self::Err> {
+        self.count = self.count.checked_add(1).ok_or(SubscriptionCountOverflow)?;
+
+        Ok(())
+    }
+}
+
+impl ProjectCandidate<StudentSubscribedToCourse> for NumberOfStudentSubscriptions {
+    fn project_candidate(
+        &mut self,
+        _: ProjectionEvent<'_, StudentSubscribedToCourse, Candidate>,
+    ) -> Result<(), Self::Err> {
+        self.count = self.count.checked_add(1).ok_or(SubscriptionCountOverflow)?;
+
+        Ok(())
     }
 }